@@ -0,0 +1,55 @@
+//! 构建时校验 src/shaders 下的着色器 - 校验规则见 src/material/shader_check.rs
+//! * 每个 GLSL 着色器都需有对应的 WGSL 版本 (`*.vert.wgsl`, `*.frag.wgsl`)
+//! * 由 GLSL 的 Uniform 块生成 $OUT_DIR/uniform_size.rs (大小与成员布局), 供 src/effect/uniform_size.rs 引入
+
+use std::{env, fmt::Write};
+
+include!("src/material/shader_check.rs");
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/material/shader_check.rs");
+    println!("cargo:rerun-if-changed={}", SHADER_DIR);
+
+    let names = shader_names();
+
+    let mut errors = vec![];
+    for name in names.iter() {
+        if let Err(err) = check_shader(name) {
+            errors.push(err);
+        }
+    }
+    // 顶点与片元的 Uniform 块需一致
+    for name in names.iter().filter(|v| v.ends_with(".vert")) {
+        if let Err(err) = check_stage_pair(name) {
+            errors.push(err);
+        }
+    }
+
+    if !errors.is_empty() {
+        panic!("shader check failed:\n{}", errors.join("\n"));
+    }
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("uniform_size.rs");
+    fs::write(out, uniform_size(&names)).unwrap();
+}
+
+/// 各着色器 Uniform 块的 f32 个数与 (成员名称, 字节偏移) - 顶点与片元已校验一致, 取顶点着色器
+fn uniform_size(names: &[String]) -> String {
+    let mut result = String::from("// 由 build.rs 根据 src/shaders 中的 Uniform 块生成\n");
+    for name in names.iter().filter(|v| v.ends_with(".vert")) {
+        let stem = name.trim_end_matches(".vert");
+        if let Ok(Some((members, span))) = uniform_of_glsl(name, &[]) {
+            writeln!(result).unwrap();
+            if names.contains(&format!("{}.frag", stem)) {
+                writeln!(result, "/// {}.vert / {}.frag", stem, stem).unwrap();
+            } else {
+                writeln!(result, "/// {}.vert", stem).unwrap();
+            }
+            writeln!(result, "pub const UNIFORM_FLOATS_{}: usize = {};", stem.to_uppercase(), span / 4).unwrap();
+            let members: Vec<String> = members.iter().map(|(name, offset, _)| format!("(\"{}\", {})", name, offset)).collect();
+            writeln!(result, "pub const UNIFORM_LAYOUT_{}: &[(&str, usize)] = &[{}];", stem.to_uppercase(), members.join(", ")).unwrap();
+        }
+    }
+    result
+}
@@ -257,7 +257,9 @@ impl Node for RenderNode {
                             final_targets,
                             final_depth_and_stencil,
                             postprocess.target_type.clone(),
-                            finalcolorformat
+                            finalcolorformat,
+                            Some(postprocess.viewport),
+                            None
                         ) {
                             let mut renderpass = commands.begin_render_pass(
                                 &wgpu::RenderPassDescriptor {
@@ -278,10 +280,8 @@ impl Node for RenderNode {
                                 }
                             );
             
-                            let (x, y, w, h) = postprocess.viewport;
-                            log::warn!("Viewport Final: {:?}", (x, y, w, h));
-                            renderpass.set_viewport(x, y, w, h, 0., 1.);
-                            draw.draw(&mut renderpass);
+                            log::warn!("Viewport Final: {:?}", postprocess.viewport);
+                            draw.draw(None, Some(&mut renderpass));
                         }
                     },
                     Err(_) => {
@@ -371,6 +371,15 @@ impl Plugin for PluginTest {
         EffectBlurGauss::setup(&renderdevice, &mut resources, &asset_samplers);
         EffectImageMask::setup(&renderdevice, &mut resources, &asset_samplers);
         EffectClipSdf::setup(&renderdevice, &mut resources, &asset_samplers);
+        EffectDepthFog::setup(&renderdevice, &mut resources, &asset_samplers);
+        EffectBlurBokehDepth::setup(&renderdevice, &mut resources, &asset_samplers);
+        EffectDropShadow::setup(&renderdevice, &mut resources, &asset_samplers);
+        EffectGlow::setup(&renderdevice, &mut resources, &asset_samplers);
+        EffectOutline::setup(&renderdevice, &mut resources, &asset_samplers);
+        EffectJumpFlood::setup(&renderdevice, &mut resources, &asset_samplers);
+        EffectChromaticAberration::setup(&renderdevice, &mut resources, &asset_samplers);
+        EffectFilmGrain::setup(&renderdevice, &mut resources, &asset_samplers);
+        EffectPixelate::setup(&renderdevice, &mut resources, &asset_samplers);
 
         app.insert_resource(ResImageEffectResource(resources));
         app.insert_resource(TestPostprocess {
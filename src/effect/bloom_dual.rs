@@ -21,6 +21,10 @@ impl BloomDual {
     ) -> bool {
         self.radius > 0 && self.iteration > 0 && self.intensity > 0.
     }
+    /// 采样范围 - 像素, 同 BlurDual
+    pub fn reach(&self, size: (u32, u32)) -> Option<(u32, u32)> {
+        BlurDual { radius: self.radius, iteration: self.iteration, intensity: self.intensity, simplified_up: true }.reach(size)
+    }
 }
 impl Default for BloomDual {
     fn default() -> Self {
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use crate::prelude::{ImageEffectUniformBuffer, SingleImageEffectResource};
+use super::uniform::*;
 
 
 /// 散景模糊
@@ -27,6 +28,11 @@ impl BlurBokeh {
     ) -> bool {
         self.radius > 0. && self.iteration > 0
     }
+    /// 采样范围 - 像素, 螺旋采样半径不超过 radius * sqrt(1 + 3 * iteration)
+    pub fn reach(&self, _size: (u32, u32)) -> Option<(u32, u32)> {
+        let reach = (self.radius * (1. + 3. * self.iteration as f32).sqrt()).ceil() as u32;
+        Some((reach, reach))
+    }
 }
 
 pub struct BlurBokehRenderer {
@@ -52,31 +58,23 @@ impl super::TEffectForBuffer for BlurBokehRenderer {
         src_premultiplied: bool,
         dst_premultiply: bool,
     ) -> &pi_render::rhi::buffer::Buffer {
-        let mut temp = vec![
-
-        ];
-        geo_matrix.iter().for_each(|v| { temp.push(*v) });
-        temp.push(tex_matrix.0);
-        temp.push(tex_matrix.1);
-        temp.push(tex_matrix.2);
-        temp.push(tex_matrix.3);
-        
-        temp.push(self.param.center_x);
-        temp.push(self.param.center_y);
-        temp.push(self.param.radius as f32 / dst_size.0 as f32);
-        temp.push(self.param.iteration as f32);
-
-        temp.push(self.param.start);
-        temp.push(self.param.fade);
-        temp.push(depth);
-        temp.push(alpha);
-
-        if src_premultiplied { temp.push(1.); } else { temp.push(0.); }
-        if dst_premultiply { temp.push(1.); } else { temp.push(0.); }
-        temp.push(0.);
-        temp.push(0.);
+        let data = BlurCenterUniform {
+            vertex_matrix: mat4(geo_matrix),
+            diffuse_mat: vec4(tex_matrix),
+            center: [self.param.center_x, self.param.center_y],
+            offset: self.param.radius as f32 / dst_size.0 as f32,
+            iteration: self.param.iteration as f32,
+            start: self.param.start,
+            fade: self.param.fade,
+            depth,
+            alpha,
+            src_preimultiplied: flag(src_premultiplied),
+            dst_preimultiply: flag(dst_premultiply),
+            _wasm_0: 0.,
+            _wasm_1: 0.,
+        };
 
-        queue.write_buffer(self.uniform.buffer(), 0, bytemuck::cast_slice(&temp));
+        queue.write_buffer(self.uniform.buffer(), 0, bytemuck::bytes_of(&data));
         self.uniform.buffer()
     }
 }
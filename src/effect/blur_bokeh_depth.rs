@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use crate::prelude::{ImageEffectUniformBuffer, SingleImageEffectResource};
+use super::{uniform::*, BlurBokeh, DepthInput};
+
+/// 景深散景模糊 - 模糊半径随与焦点的距离变化, 需要 PostProcess::depth
+#[derive(Clone, Copy, Debug)]
+pub struct BlurBokehDepth {
+    /// 使用 radius, iteration; 径向参数 center, start, fade 不生效
+    pub bokeh: BlurBokeh,
+    /// 焦点距离 - 相机空间
+    pub focus: f32,
+    /// 与焦点距离达到 range 时模糊半径达到 radius
+    pub range: f32,
+}
+
+impl BlurBokehDepth {
+    pub fn is_enabled(&self) -> bool {
+        self.bokeh.is_enabled() && self.range > 0.
+    }
+    /// 采样范围 - 像素, 同 BlurBokeh
+    pub fn reach(&self, size: (u32, u32)) -> Option<(u32, u32)> {
+        self.bokeh.reach(size)
+    }
+}
+
+pub struct BlurBokehDepthRenderer {
+    pub(crate) param: BlurBokehDepth,
+    pub(crate) depth: DepthInput,
+    pub(crate) uniform: Arc<ImageEffectUniformBuffer>,
+}
+impl BlurBokehDepthRenderer {
+    pub fn new(param: &BlurBokehDepth, depth: &DepthInput, resource: &SingleImageEffectResource) -> Self {
+        Self { param: param.clone(), depth: depth.clone(), uniform: resource.uniform_buffer() }
+    }
+}
+
+impl super::TEffectForBuffer for BlurBokehDepthRenderer {
+    fn buffer(&self, 
+        _: u64,
+        geo_matrix: &[f32],
+        tex_matrix: (f32, f32, f32, f32),
+        alpha: f32, depth: f32,
+        _device: &pi_render::rhi::device::RenderDevice,
+        queue: &pi_render::rhi::RenderQueue,
+        _: (u32, u32),
+        dst_size: (u32, u32),
+        src_premultiplied: bool,
+        dst_premultiply: bool,
+    ) -> &pi_render::rhi::buffer::Buffer {
+        let data = BlurBokehDepthUniform {
+            vertex_matrix: mat4(geo_matrix),
+            diffuse_mat: vec4(tex_matrix),
+            depth_mat: vec4(self.depth.texture.get_tilloff()),
+            offset: self.param.bokeh.radius / dst_size.0 as f32,
+            iteration: self.param.bokeh.iteration as f32,
+            near: self.depth.near,
+            far: self.depth.far,
+            focus: self.param.focus,
+            range: self.param.range,
+            reversed: flag(self.depth.reversed),
+            depth,
+            alpha,
+            src_preimultiplied: flag(src_premultiplied),
+            dst_preimultiply: flag(dst_premultiply),
+            _wasm_0: 0.,
+        };
+
+        queue.write_buffer(self.uniform.buffer(), 0, bytemuck::bytes_of(&data));
+        self.uniform.buffer()
+    }
+}
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use crate::prelude::{ImageEffectUniformBuffer, SingleImageEffectResource};
+use super::uniform::*;
 
 
 /// 定向模糊
@@ -23,6 +24,13 @@ impl BlurDirect {
     ) -> bool {
         self.radius > 0 && self.iteration > 0 && (self.direct_x > 0. || self.direct_y > 0.)
     }
+    /// 采样范围 - 像素, 沿方向采样 iteration 次, 每次偏移 radius
+    pub fn reach(&self, _size: (u32, u32)) -> Option<(u32, u32)> {
+        let len = (self.direct_x * self.direct_x + self.direct_y * self.direct_y).sqrt();
+        if len <= 0. { return Some((0, 0)); }
+        let dist = self.radius as f32 * self.iteration as f32;
+        Some(((dist * self.direct_x.abs() / len).ceil() as u32, (dist * self.direct_y.abs() / len).ceil() as u32))
+    }
 }
 pub struct BlurDirectRenderer {
     pub(crate) param: BlurDirect,
@@ -47,26 +55,19 @@ impl super::TEffectForBuffer for BlurDirectRenderer {
         src_premultiplied: bool,
         dst_premultiply: bool,
     ) -> &pi_render::rhi::buffer::Buffer {
-        let mut temp = vec![
-
-        ];
-        geo_matrix.iter().for_each(|v| { temp.push(*v) });
-        temp.push(tex_matrix.0);
-        temp.push(tex_matrix.1);
-        temp.push(tex_matrix.2);
-        temp.push(tex_matrix.3);
-        
-        temp.push(self.param.direct_x);
-        temp.push(self.param.direct_y);
-        temp.push(self.param.radius as f32 / dst_size.0 as f32);
-        temp.push(self.param.iteration as f32);
-
-        temp.push(depth);
-        temp.push(alpha);
-        if src_premultiplied { temp.push(1.); } else { temp.push(0.); }
-        if dst_premultiply { temp.push(1.); } else { temp.push(0.); }
+        let data = BlurDirectUniform {
+            vertex_matrix: mat4(geo_matrix),
+            diffuse_mat: vec4(tex_matrix),
+            direct: [self.param.direct_x, self.param.direct_y],
+            offset: self.param.radius as f32 / dst_size.0 as f32,
+            iteration: self.param.iteration as f32,
+            depth,
+            alpha,
+            src_preimultiplied: flag(src_premultiplied),
+            dst_preimultiply: flag(dst_premultiply),
+        };
 
-        queue.write_buffer(self.uniform.buffer(), 0, bytemuck::cast_slice(&temp));
+        queue.write_buffer(self.uniform.buffer(), 0, bytemuck::bytes_of(&data));
         self.uniform.buffer()
 
     }
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use crate::prelude::{ImageEffectUniformBuffer, SingleImageEffectResource};
+use super::uniform::*;
 
 /// Dual 模糊
 #[derive(Clone, Copy, Debug)]
@@ -27,6 +28,12 @@ impl BlurDual {
     ) -> bool {
         self.radius > 0 && self.iteration > 0 && self.intensity > 0.0
     }
+    /// 采样范围 - 像素, 每级降采样偏移 radius 且分辨率减半
+    pub fn reach(&self, _size: (u32, u32)) -> Option<(u32, u32)> {
+        let level = BlurDualRendererList::MAX_LEVEL.min(self.iteration as usize) as u32;
+        let reach = (self.radius as u32 + 1) << (level + 1);
+        Some((reach, reach))
+    }
 }
 
 pub struct BlurDualRendererList {
@@ -85,26 +92,19 @@ impl super::TEffectForBuffer for BlurDualRenderer {
         src_premultiplied: bool,
         dst_premultiply: bool,
     ) -> &pi_render::rhi::buffer::Buffer {
-        let mut temp = vec![
-
-        ];
-        geo_matrix.iter().for_each(|v| { temp.push(*v) });
-        temp.push(tex_matrix.0);
-        temp.push(tex_matrix.1);
-        temp.push(tex_matrix.2);
-        temp.push(tex_matrix.3);
-
-        temp.push(self.param.radius as f32 / dst_size.0 as f32);
-        temp.push(self.param.radius as f32 / dst_size.1 as f32);
-        temp.push(self.param.intensity);
-        if self.isup { temp.push(1.); } else { temp.push(0.); };
-
-        temp.push(depth);
-        temp.push(alpha);
-        if src_premultiplied { temp.push(1.); } else { temp.push(0.); }
-        if dst_premultiply { temp.push(1.); } else { temp.push(0.); }
+        let data = BlurDualUniform {
+            vertex_matrix: mat4(geo_matrix),
+            diffuse_mat: vec4(tex_matrix),
+            offset: [self.param.radius as f32 / dst_size.0 as f32, self.param.radius as f32 / dst_size.1 as f32],
+            intensity: self.param.intensity,
+            dualmode: flag(self.isup),
+            depth,
+            alpha,
+            src_preimultiplied: flag(src_premultiplied),
+            dst_preimultiply: flag(dst_premultiply),
+        };
 
-        queue.write_buffer(self.uniform.buffer(), 0, bytemuck::cast_slice(&temp));
+        queue.write_buffer(self.uniform.buffer(), 0, bytemuck::bytes_of(&data));
         self.uniform.buffer()
     }
 }
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use crate::prelude::{ImageEffectUniformBuffer, SingleImageEffectResource};
+use super::uniform::*;
 
 /// Dual 模糊
 #[derive(Clone, Copy, Debug)]
@@ -21,6 +22,11 @@ impl BlurGauss {
     ) -> bool {
         self.radius > 0.0
     }
+    /// 采样范围 - 像素, 与着色器中 support 一致
+    pub fn reach(&self, _size: (u32, u32)) -> Option<(u32, u32)> {
+        let support = ((1.5 * self.radius).ceil() * 2.0) as u32 + 1;
+        Some((support, support))
+    }
 }
 
 #[derive(Clone)]
@@ -47,24 +53,19 @@ impl super::TEffectForBuffer for BlurGaussRenderer {
         src_premultiplied: bool,
         dst_premultiply: bool,
     ) -> &pi_render::rhi::buffer::Buffer {
-        let mut temp = vec![];
-        geo_matrix.iter().for_each(|v| { temp.push(*v) });
-        temp.push(tex_matrix.0);
-        temp.push(tex_matrix.1);
-        temp.push(tex_matrix.2);
-        temp.push(tex_matrix.3);
-        
-        temp.push(1.0 / src_size.0 as f32);
-        temp.push(1.0 / src_size.1 as f32);
-        temp.push(self.param.radius as f32);
-        if self.ishorizon { temp.push(1.); } else { temp.push(0.); };
-
-        temp.push(depth);
-        temp.push(alpha);
-        if src_premultiplied { temp.push(1.); } else { temp.push(0.); }
-        if dst_premultiply { temp.push(1.); } else { temp.push(0.); }
+        let data = BlurGaussUniform {
+            vertex_matrix: mat4(geo_matrix),
+            diffuse_mat: vec4(tex_matrix),
+            texture_size: [1.0 / src_size.0 as f32, 1.0 / src_size.1 as f32],
+            blur_radius: self.param.radius as f32,
+            horizontal: flag(self.ishorizon),
+            depth,
+            alpha,
+            src_preimultiplied: flag(src_premultiplied),
+            dst_preimultiply: flag(dst_premultiply),
+        };
 
-        queue.write_buffer(self.uniform.buffer(), 0, bytemuck::cast_slice(&temp));
+        queue.write_buffer(self.uniform.buffer(), 0, bytemuck::bytes_of(&data));
         self.uniform.buffer()
     }
 }
@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use crate::prelude::{ImageEffectUniformBuffer, SingleImageEffectResource};
+use super::uniform::*;
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EChromaticAberrationMode {
+    /// 由中心向外偏移
+    Radial,
+    /// 沿固定方向偏移
+    Direct,
+}
+
+/// 色差 - 红, 蓝通道向相反方向偏移, 绿色通道不动
+#[derive(Clone, Copy, Debug)]
+pub struct ChromaticAberration {
+    pub mode: EChromaticAberrationMode,
+    /// 偏移中心点坐标 x - 渲染范围 [-1, 1]
+    pub center_x: f32,
+    /// 偏移中心点坐标 y - 渲染范围 [-1, 1]
+    pub center_y: f32,
+    /// 方向 x 轴 - Direct 模式红色通道沿该方向偏移
+    pub direct_x: f32,
+    /// 方向 y 轴
+    pub direct_y: f32,
+    /// 强度 - 像素, 红蓝通道的最大偏移
+    pub strength: f32,
+    /// 衰减 - 偏移乘以到中心距离的 falloff 次幂; 0 时不衰减
+    pub falloff: f32,
+}
+
+impl Default for ChromaticAberration {
+    fn default() -> Self {
+        Self { mode: EChromaticAberrationMode::Radial, center_x: 0., center_y: 0., direct_x: 1., direct_y: 0., strength: 0., falloff: 0. }
+    }
+}
+
+impl ChromaticAberration {
+    pub fn is_enabled(&self) -> bool {
+        self.strength > 0. && (self.mode == EChromaticAberrationMode::Radial || self.direct_x != 0. || self.direct_y != 0.)
+    }
+    /// 采样范围 - 像素
+    pub fn reach(&self, _size: (u32, u32)) -> Option<(u32, u32)> {
+        let strength = self.strength.max(0.).ceil() as u32;
+        Some((strength, strength))
+    }
+}
+pub struct ChromaticAberrationRenderer {
+    pub(crate) param: ChromaticAberration,
+    pub(crate) uniform: Arc<ImageEffectUniformBuffer>,
+}
+impl ChromaticAberrationRenderer {
+    pub fn new(param: &ChromaticAberration, resources: &SingleImageEffectResource) -> Self {
+        Self { param: param.clone(), uniform: resources.uniform_buffer() }
+    }
+}
+impl super::TEffectForBuffer for ChromaticAberrationRenderer {
+    fn buffer(&self, 
+        _: u64,
+        geo_matrix: &[f32],
+        tex_matrix: (f32, f32, f32, f32),
+        alpha: f32, depth: f32,
+        _device: &pi_render::rhi::device::RenderDevice,
+        queue: &pi_render::rhi::RenderQueue,
+        src_size: (u32, u32),
+        _: (u32, u32),
+        src_premultiplied: bool,
+        dst_premultiply: bool,
+    ) -> &pi_render::rhi::buffer::Buffer {
+        let len = (self.param.direct_x * self.param.direct_x + self.param.direct_y * self.param.direct_y).sqrt().max(f32::EPSILON);
+        let data = ChromaticAberrationUniform {
+            vertex_matrix: mat4(geo_matrix),
+            diffuse_mat: vec4(tex_matrix),
+            centerx: self.param.center_x,
+            centery: self.param.center_y,
+            direct_x: self.param.direct_x / len,
+            direct_y: self.param.direct_y / len,
+            texel: [1.0 / src_size.0 as f32, 1.0 / src_size.1 as f32],
+            strength: self.param.strength,
+            falloff: self.param.falloff.max(0.),
+            mode: match self.param.mode {
+                EChromaticAberrationMode::Radial => 0.,
+                EChromaticAberrationMode::Direct => 1.,
+            },
+            depth,
+            alpha,
+            src_preimultiplied: flag(src_premultiplied),
+            dst_preimultiply: flag(dst_premultiply),
+            _wasm_0: 0.,
+            _wasm_1: 0.,
+            _wasm_2: 0.,
+        };
+
+        queue.write_buffer(self.uniform.buffer(), 0, bytemuck::bytes_of(&data));
+        self.uniform.buffer()
+    }
+}
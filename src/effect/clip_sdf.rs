@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use crate::prelude::{ImageEffectUniformBuffer, SingleImageEffectResource};
+use super::uniform::*;
 
 
 ///
@@ -143,11 +144,13 @@ impl ClipSdf {
 }
 pub struct ClipSdfRenderer {
     pub(crate) param: ClipSdf,
+    /// 形状因子低于该值的片元被丢弃 - 写入模板时使用
+    pub(crate) cutoff: f32,
     pub(crate) uniform: Arc<ImageEffectUniformBuffer>,
 }
 impl ClipSdfRenderer {
     pub fn new(param: &ClipSdf, resource: &SingleImageEffectResource) -> Self {
-        Self { param: param.clone(), uniform: resource.uniform_buffer() }
+        Self { param: param.clone(), cutoff: 0., uniform: resource.uniform_buffer() }
     }
 }
 impl super::TEffectForBuffer for ClipSdfRenderer {
@@ -163,26 +166,21 @@ impl super::TEffectForBuffer for ClipSdfRenderer {
         src_premultiplied: bool,
         dst_premultiply: bool,
     ) -> &pi_render::rhi::buffer::Buffer {
-        let mut temp = vec![];
-        geo_matrix.iter().for_each(|v| { temp.push(*v) });
-        temp.push(tex_matrix.0);
-        temp.push(tex_matrix.1);
-        temp.push(tex_matrix.2);
-        temp.push(tex_matrix.3);
-        
-        self.param.data.iter().for_each(|v| { temp.push(*v) });
-
-        temp.push(self.param.mode);
-        temp.push(depth);
-        temp.push(alpha);
-        if src_premultiplied { temp.push(1.); } else { temp.push(0.); }
-
-        if dst_premultiply { temp.push(1.); } else { temp.push(0.); }
-        temp.push(0.);
-        temp.push(0.);
-        temp.push(0.);
+        let data = ClipSdfUniform {
+            vertex_matrix: mat4(geo_matrix),
+            diffuse_mat: vec4(tex_matrix),
+            clip_sdf: self.param.data,
+            mode: self.param.mode,
+            depth,
+            alpha,
+            src_preimultiplied: flag(src_premultiplied),
+            dst_preimultiply: flag(dst_premultiply),
+            cutoff: self.cutoff,
+            _wasm_1: 0.,
+            _wasm_2: 0.,
+        };
 
-        queue.write_buffer(self.uniform.buffer(), 0, bytemuck::cast_slice(&temp));
+        queue.write_buffer(self.uniform.buffer(), 0, bytemuck::bytes_of(&data));
         self.uniform.buffer()
     }
 }
@@ -3,6 +3,7 @@ use std::sync::Arc;
 use crate::prelude::ImageEffectUniformBuffer;
 
 use super::{color_filter::ColorFilter, color_balance::ColorBalance, color_scale::ColorScale, hsb::HSB, vignette::Vignette};
+use super::uniform::*;
 
 
 pub struct ColorEffectRenderer {
@@ -14,6 +15,24 @@ pub struct ColorEffectRenderer {
     pub(crate) uniform: Arc<ImageEffectUniformBuffer>,
 }
 
+impl ColorEffectRenderer {
+    pub const DEFINE_COLOR_BALANCE: u32 = 1 << 0;
+    pub const DEFINE_HSB: u32           = 1 << 1;
+    pub const DEFINE_COLOR_SCALE: u32   = 1 << 2;
+    pub const DEFINE_VIGNETTE: u32      = 1 << 3;
+    pub const DEFINE_COLOR_FILTER: u32  = 1 << 4;
+    /// 实际启用的子效果 - 决定编译的着色器变体
+    pub fn defines(&self) -> u32 {
+        let mut defines = 0;
+        if self.balance.as_ref().map_or(false, |v| v.is_enabled()) { defines |= Self::DEFINE_COLOR_BALANCE; }
+        if self.hsb.as_ref().map_or(false, |v| v.is_enabled()) { defines |= Self::DEFINE_HSB; }
+        if self.scale.as_ref().map_or(false, |v| v.is_enabled()) { defines |= Self::DEFINE_COLOR_SCALE; }
+        if self.vignette.as_ref().map_or(false, |v| v.is_enabled()) { defines |= Self::DEFINE_VIGNETTE; }
+        if self.filter.as_ref().map_or(false, |v| v.is_enabled()) { defines |= Self::DEFINE_COLOR_FILTER; }
+        defines
+    }
+}
+
 impl super::TEffectForBuffer for ColorEffectRenderer {
     fn buffer(&self, 
         _: u64,
@@ -27,31 +46,24 @@ impl super::TEffectForBuffer for ColorEffectRenderer {
         src_premultiplied: bool,
         dst_premultiply: bool,
     ) -> &pi_render::rhi::buffer::Buffer {
-        let mut temp = vec![
-
-        ];
-        geo_matrix.iter().for_each(|v| { temp.push(*v) });
-        temp.push(tex_matrix.0);
-        temp.push(tex_matrix.1);
-        temp.push(tex_matrix.2);
-        temp.push(tex_matrix.3);
-
-        ColorBalance::collect(self.balance.as_ref(), &mut temp);
-        HSB::collect(self.hsb.as_ref(), &mut temp);
-        ColorScale::collect(self.scale.as_ref(), &mut temp);
-        Vignette::collect(self.vignette.as_ref(), &mut temp);
-        ColorFilter::collect(self.filter.as_ref(), &mut temp);
-
-        temp.push(depth);
-        temp.push(alpha);
-        if src_premultiplied { temp.push(1.); } else { temp.push(0.); }
-
-        if dst_premultiply { temp.push(1.); } else { temp.push(0.); }
-        temp.push(0.);
-        temp.push(0.);
-        temp.push(0.);
-
-        queue.write_buffer(self.uniform.buffer(), 0, bytemuck::cast_slice(&temp));
+        let data = ColorEffectUniform {
+            vertex_matrix: mat4(geo_matrix),
+            diffuse_mat: vec4(tex_matrix),
+            color_balance: ColorBalance::uniform(self.balance.as_ref()),
+            hsb: HSB::uniform(self.hsb.as_ref()),
+            color_scale: ColorScale::uniform(self.scale.as_ref()),
+            vignette: Vignette::uniform(self.vignette.as_ref()),
+            color_filter: ColorFilter::uniform(self.filter.as_ref()),
+            depth,
+            alpha,
+            src_preimultiplied: flag(src_premultiplied),
+            dst_preimultiply: flag(dst_premultiply),
+            _wasm_0: 0.,
+            _wasm_1: 0.,
+            _wasm_2: 0.,
+        };
+
+        queue.write_buffer(self.uniform.buffer(), 0, bytemuck::bytes_of(&data));
         self.uniform.buffer()
     }
 }
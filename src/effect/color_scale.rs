@@ -32,21 +32,18 @@ impl ColorScale {
         self.shadow_in != 0 || self.shadow_out != 0 || self.mid != 1.0 || self.highlight_in != 255 || self.highlight_out != 255
     }
     /// F32x6
-    pub fn collect(item: Option<&Self>, list: &mut Vec<f32>) {
+    pub fn uniform(item: Option<&Self>) -> [f32; 6] {
         if let Some(item) = item {
-            list.push(1.);
-            list.push(item.shadow_in as f32 / 255.);
-            list.push(item.shadow_out as f32 / 255.);
-            list.push(item.mid);
-            list.push(item.highlight_in as f32 / 255.);
-            list.push(item.highlight_out as f32 / 255.);
+            [
+                1.,
+                item.shadow_in as f32 / 255.,
+                item.shadow_out as f32 / 255.,
+                item.mid,
+                item.highlight_in as f32 / 255.,
+                item.highlight_out as f32 / 255.,
+            ]
         } else {
-            list.push(0.);
-            list.push(0.);
-            list.push(0.);
-            list.push(0.);
-            list.push(0.);
-            list.push(0.);
+            [0.; 6]
         }
     }
 }
\ No newline at end of file
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use crate::prelude::{ImageEffectUniformBuffer, SingleImageEffectResource};
+use super::uniform::*;
 
 #[derive(Clone, Copy, Debug)]
 pub struct CopyIntensity {
@@ -55,31 +56,26 @@ impl super::TEffectForBuffer for CopyIntensityRenderer {
         src_premultiplied: bool,
         dst_premultiply: bool,
     ) -> &pi_render::rhi::buffer::Buffer {
-        let mut temp = vec![
+        let data = CopyUniform {
+            vertex_matrix: mat4(geo_matrix),
+            diffuse_mat: vec4(tex_matrix),
+            intensity: self.param.intensity,
+            polygon_n: self.param.polygon as f32,
+            radius: self.param.radius,
+            rotate: self.param.angle,
+            bg_color: [
+                self.param.bg_color.0 as f32 / 255.,
+                self.param.bg_color.1 as f32 / 255.,
+                self.param.bg_color.2 as f32 / 255.,
+                self.param.bg_color.3 as f32 / 255.,
+            ],
+            depth,
+            alpha,
+            src_preimultiplied: flag(src_premultiplied),
+            dst_preimultiply: flag(dst_premultiply),
+        };
 
-        ];
-        geo_matrix.iter().for_each(|v| { temp.push(*v) });
-        temp.push(tex_matrix.0);
-        temp.push(tex_matrix.1);
-        temp.push(tex_matrix.2);
-        temp.push(tex_matrix.3);
-
-        temp.push(self.param.intensity);
-        temp.push(self.param.polygon as f32);
-        temp.push(self.param.radius);
-        temp.push(self.param.angle);
-        
-        temp.push(self.param.bg_color.0 as f32 / 255.);
-        temp.push(self.param.bg_color.1 as f32 / 255.);
-        temp.push(self.param.bg_color.2 as f32 / 255.);
-        temp.push(self.param.bg_color.3 as f32 / 255.);
-
-        temp.push(depth);
-        temp.push(alpha);
-        if src_premultiplied { temp.push(1.); } else { temp.push(0.); }
-        if dst_premultiply { temp.push(1.); } else { temp.push(0.); }
-
-        queue.write_buffer(self.uniform.buffer(), 0, bytemuck::cast_slice(&temp));
+        queue.write_buffer(self.uniform.buffer(), 0, bytemuck::bytes_of(&data));
         self.uniform.buffer()
     }
 }
\ No newline at end of file
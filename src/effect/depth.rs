@@ -0,0 +1,23 @@
+use crate::prelude::PostprocessTexture;
+
+/// 场景深度输入 - 深度相关效果使用
+#[derive(Clone)]
+pub struct DepthInput {
+    /// 深度纹理 - 视图需为 DepthOnly, 使用区域与源内容对应
+    pub texture: PostprocessTexture,
+    /// 相机近平面距离
+    pub near: f32,
+    /// 相机远平面距离
+    pub far: f32,
+    /// 是否为反转深度 - 近处为 1, 远处为 0
+    pub reversed: bool,
+}
+
+impl DepthInput {
+    pub fn new(texture: PostprocessTexture, near: f32, far: f32) -> Self {
+        Self { texture, near, far, reversed: false }
+    }
+    pub fn is_enabled(&self) -> bool {
+        self.near > 0. && self.far > self.near
+    }
+}
@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use crate::prelude::{ImageEffectUniformBuffer, SingleImageEffectResource};
+use super::{uniform::*, DepthInput};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EFogMode {
+    /// start 到 end 之间线性增加
+    Linear,
+    /// 1 - exp(-density * z)
+    Exp,
+    /// 1 - exp(-(density * z)^2)
+    Exp2,
+}
+
+/// 深度雾 - 需要 PostProcess::depth
+#[derive(Clone, Copy, Debug)]
+pub struct DepthFog {
+    /// 雾颜色 - alpha 为最大浓度
+    pub color: (u8, u8, u8, u8),
+    pub mode: EFogMode,
+    /// 线性模式 雾起始距离
+    pub start: f32,
+    /// 线性模式 雾完全覆盖距离
+    pub end: f32,
+    /// 指数模式 雾密度
+    pub density: f32,
+}
+
+impl Default for DepthFog {
+    fn default() -> Self {
+        Self {
+            color: (255, 255, 255, 255),
+            mode: EFogMode::Linear,
+            start: 0.,
+            end: 100.,
+            density: 0.02,
+        }
+    }
+}
+
+impl DepthFog {
+    pub fn is_enabled(&self) -> bool {
+        self.color.3 > 0 && match self.mode {
+            EFogMode::Linear => self.end > self.start,
+            _ => self.density > 0.,
+        }
+    }
+}
+
+pub struct DepthFogRenderer {
+    pub(crate) param: DepthFog,
+    pub(crate) depth: DepthInput,
+    pub(crate) uniform: Arc<ImageEffectUniformBuffer>,
+}
+impl DepthFogRenderer {
+    pub fn new(param: &DepthFog, depth: &DepthInput, resource: &SingleImageEffectResource) -> Self {
+        Self { param: param.clone(), depth: depth.clone(), uniform: resource.uniform_buffer() }
+    }
+}
+
+impl super::TEffectForBuffer for DepthFogRenderer {
+    fn buffer(&self, 
+        _: u64,
+        geo_matrix: &[f32],
+        tex_matrix: (f32, f32, f32, f32),
+        alpha: f32, depth: f32,
+        _device: &pi_render::rhi::device::RenderDevice,
+        queue: &pi_render::rhi::RenderQueue,
+        _: (u32, u32),
+        _: (u32, u32),
+        src_premultiplied: bool,
+        dst_premultiply: bool,
+    ) -> &pi_render::rhi::buffer::Buffer {
+        let mode = match self.param.mode {
+            EFogMode::Linear => 0.,
+            EFogMode::Exp => 1.,
+            EFogMode::Exp2 => 2.,
+        };
+        let data = DepthFogUniform {
+            vertex_matrix: mat4(geo_matrix),
+            diffuse_mat: vec4(tex_matrix),
+            depth_mat: vec4(self.depth.texture.get_tilloff()),
+            fog_color: [
+                self.param.color.0 as f32 / 255.0,
+                self.param.color.1 as f32 / 255.0,
+                self.param.color.2 as f32 / 255.0,
+                self.param.color.3 as f32 / 255.0,
+            ],
+            near: self.depth.near,
+            far: self.depth.far,
+            fog_start: self.param.start,
+            fog_end: self.param.end,
+            density: self.param.density,
+            mode,
+            reversed: flag(self.depth.reversed),
+            depth,
+            alpha,
+            src_preimultiplied: flag(src_premultiplied),
+            dst_preimultiply: flag(dst_premultiply),
+            _wasm_0: 0.,
+        };
+
+        queue.write_buffer(self.uniform.buffer(), 0, bytemuck::bytes_of(&data));
+        self.uniform.buffer()
+    }
+}
@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use crate::prelude::{ImageEffectUniformBuffer, SingleImageEffectResource};
+use super::{uniform::*, BlurGauss, BlurGaussRenderer};
+
+/// 投影 - 同 CSS drop-shadow(offset-x offset-y blur-radius color)
+/// * 源内容 alpha 经 BlurGauss 模糊, 着色偏移后绘制在源内容之下
+#[derive(Clone, Copy, Debug)]
+pub struct DropShadow {
+    /// 水平偏移 - 像素, 向右为正
+    pub offset_x: f32,
+    /// 垂直偏移 - 像素, 向下为正
+    pub offset_y: f32,
+    /// 模糊半径 - 像素, 为 0 时不模糊
+    pub blur_radius: f32,
+    /// 投影颜色
+    pub color: (u8, u8, u8, u8),
+}
+
+impl Default for DropShadow {
+    fn default() -> Self {
+        Self { offset_x: 0., offset_y: 0., blur_radius: 0., color: (0, 0, 0, 255) }
+    }
+}
+
+impl DropShadow {
+    pub fn is_enabled(&self) -> bool {
+        self.color.3 > 0
+    }
+    pub(crate) fn blur(&self) -> BlurGauss {
+        BlurGauss { radius: self.blur_radius }
+    }
+    /// 采样范围 - 像素, 偏移加模糊范围
+    pub fn reach(&self, size: (u32, u32)) -> Option<(u32, u32)> {
+        let (bx, by) = if self.blur().is_enabled() { self.blur().reach(size)? } else { (0, 0) };
+        Some((self.offset_x.abs().ceil() as u32 + bx, self.offset_y.abs().ceil() as u32 + by))
+    }
+}
+
+pub struct DropShadowRenderer {
+    pub(crate) param: DropShadow,
+    /// 模糊源内容 alpha 的水平, 垂直两步
+    pub(crate) blur: (BlurGaussRenderer, BlurGaussRenderer),
+    pub(crate) uniform: Arc<ImageEffectUniformBuffer>,
+}
+impl DropShadowRenderer {
+    pub fn new(param: &DropShadow, resource: &SingleImageEffectResource) -> Self {
+        let blur = param.blur();
+        Self {
+            param: param.clone(),
+            blur: (
+                BlurGaussRenderer { param: blur, ishorizon: true, uniform: resource.uniform_buffer() },
+                BlurGaussRenderer { param: blur, ishorizon: false, uniform: resource.uniform_buffer() },
+            ),
+            uniform: resource.uniform_buffer(),
+        }
+    }
+    pub fn update(&mut self, param: &DropShadow) {
+        self.param = param.clone();
+        self.blur.0.param = param.blur();
+        self.blur.1.param = param.blur();
+    }
+    /// 合成步骤的 Uniform
+    /// * `shadow_mat`
+    ///   * 模糊后 alpha 纹理的使用区域
+    pub(crate) fn buffer(&self,
+        geo_matrix: &[f32],
+        tex_matrix: (f32, f32, f32, f32),
+        shadow_mat: (f32, f32, f32, f32),
+        alpha: f32, depth: f32,
+        queue: &pi_render::rhi::RenderQueue,
+        src_size: (u32, u32),
+        src_premultiplied: bool,
+        dst_premultiply: bool,
+    ) -> &pi_render::rhi::buffer::Buffer {
+        let data = DropShadowUniform {
+            vertex_matrix: mat4(geo_matrix),
+            diffuse_mat: vec4(tex_matrix),
+            shadow_mat: vec4(shadow_mat),
+            shadow_color: [
+                self.param.color.0 as f32 / 255.0,
+                self.param.color.1 as f32 / 255.0,
+                self.param.color.2 as f32 / 255.0,
+                self.param.color.3 as f32 / 255.0,
+            ],
+            offset: [self.param.offset_x / src_size.0 as f32, self.param.offset_y / src_size.1 as f32],
+            depth,
+            alpha,
+            src_preimultiplied: flag(src_premultiplied),
+            dst_preimultiply: flag(dst_premultiply),
+            _wasm_0: 0.,
+            _wasm_1: 0.,
+        };
+
+        queue.write_buffer(self.uniform.buffer(), 0, bytemuck::bytes_of(&data));
+        self.uniform.buffer()
+    }
+}
@@ -0,0 +1,94 @@
+use std::sync::Arc;
+
+use crate::prelude::{ImageEffectUniformBuffer, SingleImageEffectResource, PostprocessTime};
+use super::uniform::*;
+
+
+/// 胶片颗粒 - 随时间变化的程序噪声叠加到源内容
+/// * 噪声由 PostprocessTime 驱动, 同一 seed 与时间得到相同结果, 截图时可固定时间
+#[derive(Clone, Copy, Debug)]
+pub struct FilmGrain {
+    /// 颗粒强度 - [0, 1]
+    pub intensity: f32,
+    /// 颗粒尺寸 - 像素, 不小于 1
+    pub grain_size: f32,
+    /// 亮度响应 - [0, 1], 越大高光处颗粒越少
+    pub luminance_response: f32,
+    /// 单色颗粒, 否则各通道独立
+    pub monochrome: bool,
+    /// 噪声刷新频率 - 每秒次数, 0 时不随时间变化
+    pub fps: f32,
+    /// 随机种子
+    pub seed: u32,
+}
+
+impl Default for FilmGrain {
+    fn default() -> Self {
+        Self { intensity: 0., grain_size: 1., luminance_response: 0.5, monochrome: true, fps: 24., seed: 0 }
+    }
+}
+
+impl FilmGrain {
+    pub fn is_enabled(&self) -> bool {
+        self.intensity > 0.
+    }
+    /// 采样范围 - 像素; 随时间变化时每帧影响整个区域
+    pub fn reach(&self, _size: (u32, u32)) -> Option<(u32, u32)> {
+        if self.fps > 0. { None } else { Some((0, 0)) }
+    }
+    /// 噪声帧序号 - 着色器以 f32 接收, 限制在 24 位内
+    pub fn frame(&self, time: &PostprocessTime) -> u32 {
+        if self.fps > 0. {
+            ((time.time() * self.fps as f64 / 1000.).floor() as u64 % (1 << 24)) as u32
+        } else {
+            0
+        }
+    }
+}
+pub struct FilmGrainRenderer {
+    pub(crate) param: FilmGrain,
+    pub(crate) frame: u32,
+    pub(crate) uniform: Arc<ImageEffectUniformBuffer>,
+}
+impl FilmGrainRenderer {
+    pub fn new(param: &FilmGrain, resources: &SingleImageEffectResource) -> Self {
+        Self { param: param.clone(), frame: 0, uniform: resources.uniform_buffer() }
+    }
+    pub fn update(&mut self, param: &FilmGrain, time: &PostprocessTime) {
+        self.param = param.clone();
+        self.frame = param.frame(time);
+    }
+}
+impl super::TEffectForBuffer for FilmGrainRenderer {
+    fn buffer(&self, 
+        _: u64,
+        geo_matrix: &[f32],
+        tex_matrix: (f32, f32, f32, f32),
+        alpha: f32, depth: f32,
+        _device: &pi_render::rhi::device::RenderDevice,
+        queue: &pi_render::rhi::RenderQueue,
+        src_size: (u32, u32),
+        _: (u32, u32),
+        src_premultiplied: bool,
+        dst_premultiply: bool,
+    ) -> &pi_render::rhi::buffer::Buffer {
+        let data = FilmGrainUniform {
+            vertex_matrix: mat4(geo_matrix),
+            diffuse_mat: vec4(tex_matrix),
+            texel: [1.0 / src_size.0 as f32, 1.0 / src_size.1 as f32],
+            grain_size: self.param.grain_size.max(1.),
+            intensity: self.param.intensity.clamp(0., 1.),
+            luminance_response: self.param.luminance_response.clamp(0., 1.),
+            monochrome: flag(self.param.monochrome),
+            frame: self.frame as f32,
+            seed: (self.param.seed & 0xFFFFFF) as f32,
+            depth,
+            alpha,
+            src_preimultiplied: flag(src_premultiplied),
+            dst_preimultiply: flag(dst_premultiply),
+        };
+
+        queue.write_buffer(self.uniform.buffer(), 0, bytemuck::bytes_of(&data));
+        self.uniform.buffer()
+    }
+}
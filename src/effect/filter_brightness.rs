@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use crate::prelude::{ImageEffectUniformBuffer, SingleImageEffectResource};
+use super::uniform::*;
 
 #[derive(Clone, Copy, Debug)]
 pub struct FilterBrightness {
@@ -46,32 +47,23 @@ impl super::TEffectForBuffer for FilterBrightnessRenderer {
         src_premultiplied: bool,
         dst_premultiply: bool,
     ) -> &pi_render::rhi::buffer::Buffer {
-        let mut temp = vec![
-
-        ];
-        geo_matrix.iter().for_each(|v| { temp.push(*v) });
-        temp.push(tex_matrix.0);
-        temp.push(tex_matrix.1);
-        temp.push(tex_matrix.2);
-        temp.push(tex_matrix.3);
-
         let threshold_x = f32::powf(self.param.threshold, 2.2);
         let mut threshold_y = threshold_x * self.param.threshold_knee;
         let threshold_z = 2. * threshold_y;
         let threshold_w = 0.25 / (threshold_y + 0.00001);
         threshold_y -= threshold_x;
-    
-        temp.push(threshold_x);
-        temp.push(threshold_y);
-        temp.push(threshold_z);
-        temp.push(threshold_w);
 
-        temp.push(depth);
-        temp.push(alpha);
-        if src_premultiplied { temp.push(1.); } else { temp.push(0.); }
-        if dst_premultiply { temp.push(1.); } else { temp.push(0.); }
+        let data = FilterBrightnessUniform {
+            vertex_matrix: mat4(geo_matrix),
+            diffuse_mat: vec4(tex_matrix),
+            threshold: [threshold_x, threshold_y, threshold_z, threshold_w],
+            depth,
+            alpha,
+            src_preimultiplied: flag(src_premultiplied),
+            dst_preimultiply: flag(dst_premultiply),
+        };
 
-        queue.write_buffer(self.uniform.buffer(), 0, bytemuck::cast_slice(&temp));
+        queue.write_buffer(self.uniform.buffer(), 0, bytemuck::bytes_of(&data));
         self.uniform.buffer()
     }
 }
\ No newline at end of file
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use crate::prelude::{ImageEffectUniformBuffer, SingleImageEffectResource};
+use super::uniform::*;
 
 #[derive(Clone, Copy, Debug)]
 pub struct FilterSobel {
@@ -18,6 +19,10 @@ impl FilterSobel {
     pub fn is_enabled(&self) -> bool {
         self.size > 0 && self.clip > 0.
     }
+    /// 采样范围 - 像素
+    pub fn reach(&self, _size: (u32, u32)) -> Option<(u32, u32)> {
+        Some((self.size as u32, self.size as u32))
+    }
 }
 
 impl Default for FilterSobel {
@@ -53,36 +58,31 @@ impl super::TEffectForBuffer for FilterSobelRenderer {
         src_premultiplied: bool,
         dst_premultiply: bool,
     ) -> &pi_render::rhi::buffer::Buffer {
-        let mut temp = vec![
-
-        ];
-        geo_matrix.iter().for_each(|v| { temp.push(*v) });
-        temp.push(tex_matrix.0);
-        temp.push(tex_matrix.1);
-        temp.push(tex_matrix.2);
-        temp.push(tex_matrix.3);
-
-        temp.push(self.param.color.0 as f32 / 255.0);
-        temp.push(self.param.color.1 as f32 / 255.0);
-        temp.push(self.param.color.2 as f32 / 255.0);
-        temp.push(self.param.color.3 as f32 / 255.0);
-
-        temp.push(self.param.bg_color.0 as f32 / 255.0);
-        temp.push(self.param.bg_color.1 as f32 / 255.0);
-        temp.push(self.param.bg_color.2 as f32 / 255.0);
-        temp.push(self.param.bg_color.3 as f32 / 255.0);
-
-        temp.push(self.param.size as f32 / dst_size.0 as f32);
-        temp.push(self.param.size as f32 / dst_size.1 as f32);
-        temp.push(self.param.clip);
-        temp.push(depth);
-
-        temp.push(alpha);
-        if src_premultiplied { temp.push(1.); } else { temp.push(0.); }
-        if dst_premultiply { temp.push(1.); } else { temp.push(0.); }
-        temp.push(0.);
+        let data = SobelUniform {
+            vertex_matrix: mat4(geo_matrix),
+            diffuse_mat: vec4(tex_matrix),
+            color: [
+                self.param.color.0 as f32 / 255.0,
+                self.param.color.1 as f32 / 255.0,
+                self.param.color.2 as f32 / 255.0,
+                self.param.color.3 as f32 / 255.0,
+            ],
+            bg_color: [
+                self.param.bg_color.0 as f32 / 255.0,
+                self.param.bg_color.1 as f32 / 255.0,
+                self.param.bg_color.2 as f32 / 255.0,
+                self.param.bg_color.3 as f32 / 255.0,
+            ],
+            diff_uv: [self.param.size as f32 / dst_size.0 as f32, self.param.size as f32 / dst_size.1 as f32],
+            clip: self.param.clip,
+            depth,
+            alpha,
+            src_preimultiplied: flag(src_premultiplied),
+            dst_preimultiply: flag(dst_premultiply),
+            wasm2: 0.,
+        };
 
-        queue.write_buffer(self.uniform.buffer(), 0, bytemuck::cast_slice(&temp));
+        queue.write_buffer(self.uniform.buffer(), 0, bytemuck::bytes_of(&data));
         self.uniform.buffer()
     }
 }
\ No newline at end of file
@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use crate::prelude::{ImageEffectUniformBuffer, SingleImageEffectResource};
+use super::{uniform::*, BlurGauss, BlurGaussRenderer};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EGlowMode {
+    /// 形状外的光晕 - 绘制在源内容之下
+    Outer,
+    /// 形状边缘向内的光晕 - 只作用于源内容不透明区域
+    Inner,
+}
+
+/// 发光 - 由源内容 alpha 的模糊结果生成彩色光晕
+#[derive(Clone, Copy, Debug)]
+pub struct Glow {
+    /// 光晕颜色 - alpha 为最大浓度
+    pub color: (u8, u8, u8, u8),
+    /// 光晕范围 - 像素, 即 alpha 的模糊半径
+    pub size: f32,
+    /// 光晕强度 - 模糊后 alpha 的放大倍数
+    pub strength: f32,
+    pub mode: EGlowMode,
+}
+
+impl Default for Glow {
+    fn default() -> Self {
+        Self { color: (255, 255, 255, 255), size: 0., strength: 1., mode: EGlowMode::Outer }
+    }
+}
+
+impl Glow {
+    pub fn is_enabled(&self) -> bool {
+        self.color.3 > 0 && self.size > 0. && self.strength > 0.
+    }
+    pub(crate) fn blur(&self) -> BlurGauss {
+        BlurGauss { radius: self.size }
+    }
+    /// 采样范围 - 像素, 同 alpha 的模糊范围
+    pub fn reach(&self, size: (u32, u32)) -> Option<(u32, u32)> {
+        self.blur().reach(size)
+    }
+}
+
+pub struct GlowRenderer {
+    pub(crate) param: Glow,
+    /// 模糊源内容 alpha 的水平, 垂直两步
+    pub(crate) blur: (BlurGaussRenderer, BlurGaussRenderer),
+    pub(crate) uniform: Arc<ImageEffectUniformBuffer>,
+}
+impl GlowRenderer {
+    pub fn new(param: &Glow, resource: &SingleImageEffectResource) -> Self {
+        let blur = param.blur();
+        Self {
+            param: param.clone(),
+            blur: (
+                BlurGaussRenderer { param: blur, ishorizon: true, uniform: resource.uniform_buffer() },
+                BlurGaussRenderer { param: blur, ishorizon: false, uniform: resource.uniform_buffer() },
+            ),
+            uniform: resource.uniform_buffer(),
+        }
+    }
+    pub fn update(&mut self, param: &Glow) {
+        self.param = param.clone();
+        self.blur.0.param = param.blur();
+        self.blur.1.param = param.blur();
+    }
+    /// 合成步骤的 Uniform
+    /// * `glow_mat`
+    ///   * 模糊后 alpha 纹理的使用区域
+    pub(crate) fn buffer(&self,
+        geo_matrix: &[f32],
+        tex_matrix: (f32, f32, f32, f32),
+        glow_mat: (f32, f32, f32, f32),
+        alpha: f32, depth: f32,
+        queue: &pi_render::rhi::RenderQueue,
+        src_premultiplied: bool,
+        dst_premultiply: bool,
+    ) -> &pi_render::rhi::buffer::Buffer {
+        let mode = match self.param.mode {
+            EGlowMode::Outer => 0.,
+            EGlowMode::Inner => 1.,
+        };
+        let data = GlowUniform {
+            vertex_matrix: mat4(geo_matrix),
+            diffuse_mat: vec4(tex_matrix),
+            glow_mat: vec4(glow_mat),
+            glow_color: [
+                self.param.color.0 as f32 / 255.0,
+                self.param.color.1 as f32 / 255.0,
+                self.param.color.2 as f32 / 255.0,
+                self.param.color.3 as f32 / 255.0,
+            ],
+            strength: self.param.strength,
+            mode,
+            depth,
+            alpha,
+            src_preimultiplied: flag(src_premultiplied),
+            dst_preimultiply: flag(dst_premultiply),
+            _wasm_0: 0.,
+            _wasm_1: 0.,
+        };
+
+        queue.write_buffer(self.uniform.buffer(), 0, bytemuck::bytes_of(&data));
+        self.uniform.buffer()
+    }
+}
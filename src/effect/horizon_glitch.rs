@@ -4,9 +4,21 @@ use std::sync::Arc;
 use pi_wy_rng::WyRng;
 use rand::{Rng, SeedableRng};
 
-use crate::prelude::{ImageEffectUniformBuffer, SingleImageEffectResource, ImageEffectInstanceBufferOffset};
+use crate::prelude::{ImageEffectUniformBuffer, SingleImageEffectResource, ImageEffectInstanceBufferOffset, PostprocessTime};
 
 use super::{CopyIntensityRenderer, CopyIntensity};
+use super::uniform::*;
+
+/// 故障条纹的随机方式
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EGlitchRandomMode {
+    /// 每个周期以 seed 重新播种 - 每个周期条纹相同
+    Repeat,
+    /// 由 seed 与周期序号确定每个周期的条纹 - 周期间不同, 相同 seed 可完全复现
+    Sequence,
+    /// 每个周期取新的随机种子 - 不可复现
+    Reseed,
+}
 
 #[derive(Clone, Debug)]
 /// 水平故障纹
@@ -19,7 +31,7 @@ pub struct HorizonGlitch {
     pub max_count: u8,
     /// 一次故障最少条纹数 - [0, 255]
     pub min_count: u8,
-    /// 一次故障持续时间 - ms - u16 最大 65000 ms
+    /// 一次故障持续时间 - ms - u16 最大 65535 ms
     pub lifetime: u16,
     /// 故障生成几率 - [0, 1]
     pub probability: f32,
@@ -29,8 +41,14 @@ pub struct HorizonGlitch {
     pub fade: f32,
     /// 是否向上
     pub is_up: bool,
-    /// 当前年龄
-    life: u16,
+    /// 随机种子 - 多个故障效果使用不同种子以互不相同
+    pub seed: u64,
+    /// 默认 Repeat - 结果确定; Reseed 需显式指定
+    pub random_mode: EGlitchRandomMode,
+    /// 当前周期内的年龄 - ms
+    life: f32,
+    /// 当前周期序号 - 由 PostprocessTime 的绝对时间确定; None 表示尚未生成条纹
+    cycle: Option<u64>,
     items: Vec<(f32, f32)>,
 }
 
@@ -41,16 +59,39 @@ impl HorizonGlitch {
         (self.max_size > 0. || self.min_size > 0.) && (self.max_count > 0 || self.min_count > 0) && self.lifetime > 0 && self.probability > 0. && self.strength > 0.
     }
 
-    pub fn update(&mut self, delta_time: u64) {
+    /// 指定种子的可复现故障
+    pub fn with_seed(mut self, seed: u64, random_mode: EGlitchRandomMode) -> Self {
+        self.seed = seed;
+        self.random_mode = random_mode;
+        self
+    }
+
+    /// 丢弃当前条纹 - 下次 update 按时间重新生成
+    pub fn reset(&mut self) {
+        self.life = 0.;
+        self.cycle = None;
+        self.items.clear();
+    }
 
-        if self.life > self.lifetime {
-            self.life = 0;
+    fn cycle_seed(&self, cycle: u64) -> u64 {
+        match self.random_mode {
+            EGlitchRandomMode::Repeat => self.seed,
+            EGlitchRandomMode::Sequence => self.seed ^ cycle.wrapping_mul(0x9E37_79B9_7F4A_7C15),
+            EGlitchRandomMode::Reseed => rand::random(),
         }
+    }
+
+    /// 按绝对时间更新 - 时间回退或跳转时直接切换到对应周期
+    pub fn update(&mut self, time: &PostprocessTime) {
+        let lifetime = self.lifetime.max(1) as f64;
+        let cycle = (time.time() / lifetime) as u64;
+        self.life = (time.time() - cycle as f64 * lifetime) as f32;
 
-        if self.life == 0 {
+        if self.cycle != Some(cycle) {
+            self.cycle = Some(cycle);
             self.items.clear();
             
-            let mut rng = WyRng::seed_from_u64(1000);
+            let mut rng = WyRng::seed_from_u64(self.cycle_seed(cycle));
 
             let scale = if self.is_up { -1.0 } else { 1.0 };
             
@@ -62,9 +103,6 @@ impl HorizonGlitch {
                 self.items.push((value, size));
             }
         }
-
-        // println!(">>>>>> {}", self.life);
-        self.life += delta_time as u16;
     }
 
     pub fn get_items(&self) -> Vec<(f32, f32)> {
@@ -74,7 +112,7 @@ impl HorizonGlitch {
 
         let scale = if self.is_up { -1.0 } else { 1.0 };
 
-        let v_distance = self.life as f32 / self.lifetime as f32 * 2.0 * scale; // 预设一屏故障总的移动范围为 2.0 屏
+        let v_distance = self.life / self.lifetime.max(1) as f32 * 2.0 * scale; // 预设一屏故障总的移动范围为 2.0 屏
 
         for i in 0..count {
             let temp = self.items.get(i).unwrap();
@@ -88,7 +126,7 @@ impl HorizonGlitch {
 
 impl Default for HorizonGlitch {
     fn default() -> Self {
-        Self { max_size: 0.1, min_size: 0.05, max_count: 6, min_count: 2, lifetime: 5000, probability: 0.5, strength: 0.05, fade: 0.05, life: 0, items: Vec::new(), is_up: true }
+        Self { max_size: 0.1, min_size: 0.05, max_count: 6, min_count: 2, lifetime: 5000, probability: 0.5, strength: 0.05, fade: 0.05, life: 0., items: Vec::new(), is_up: true, seed: 1000, random_mode: EGlitchRandomMode::Repeat, cycle: None }
     }
 }
 pub struct HorizonGlitchRenderer {
@@ -96,6 +134,7 @@ pub struct HorizonGlitchRenderer {
     pub(crate) strength: f32,
     /// 故障边界过渡因子
     pub(crate) fade: f32,
+    /// 实例数据区间 - 按故障条数量按需分配
     pub(crate) instance: Option<ImageEffectInstanceBufferOffset>,
     pub(crate) uniform: Arc<ImageEffectUniformBuffer>,
     pub(crate) copy: CopyIntensityRenderer,
@@ -106,7 +145,7 @@ impl HorizonGlitchRenderer {
             strength: param.strength,
             fade: param.fade,
             uniform: resource.uniform_buffer(),
-            instance: resource.instance_range(),
+            instance: None,
             copy: CopyIntensityRenderer::new(&CopyIntensity::default(), resource),
         }
     }
@@ -124,26 +163,78 @@ impl super::TEffectForBuffer for HorizonGlitchRenderer {
         src_premultiplied: bool,
         dst_premultiply: bool,
     ) -> &pi_render::rhi::buffer::Buffer {
-        let mut temp = vec![
-
-        ];
-        geo_matrix.iter().for_each(|v| { temp.push(*v) });
-        temp.push(tex_matrix.0);
-        temp.push(tex_matrix.1);
-        temp.push(tex_matrix.2);
-        temp.push(tex_matrix.3);
-
-        temp.push(self.strength);
-        temp.push(self.fade);
-        temp.push(depth);
-        temp.push(alpha);
-
-        if src_premultiplied { temp.push(1.); } else { temp.push(0.); }
-        if dst_premultiply { temp.push(1.); } else { temp.push(0.); }
-        temp.push(0.);
-        temp.push(0.);
-
-        queue.write_buffer(self.uniform.buffer(), 0, bytemuck::cast_slice(&temp));
+        let data = HorizonGlitchUniform {
+            vertex_matrix: mat4(geo_matrix),
+            diffuse_mat: vec4(tex_matrix),
+            strength: self.strength,
+            fade: self.fade,
+            depth,
+            alpha,
+            src_preimultiplied: flag(src_premultiplied),
+            dst_preimultiply: flag(dst_premultiply),
+            wasm0: 0.,
+            wasm1: 0.,
+        };
+
+        queue.write_buffer(self.uniform.buffer(), 0, bytemuck::bytes_of(&data));
         self.uniform.buffer()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn glitch(seed: u64, random_mode: EGlitchRandomMode) -> HorizonGlitch {
+        HorizonGlitch { probability: 1., ..Default::default() }.with_seed(seed, random_mode)
+    }
+    fn items_at(glitch: &mut HorizonGlitch, time_ms: f64) -> Vec<(f32, f32)> {
+        let mut time = PostprocessTime::default();
+        time.set_time(time_ms);
+        glitch.update(&time);
+        glitch.get_items()
+    }
+
+    #[test]
+    fn same_seed_and_time_give_same_strips() {
+        for mode in [EGlitchRandomMode::Repeat, EGlitchRandomMode::Sequence] {
+            let a = items_at(&mut glitch(7, mode), 12_345.);
+            let b = items_at(&mut glitch(7, mode), 12_345.);
+            assert!(!a.is_empty());
+            assert_eq!(a, b);
+        }
+    }
+
+    #[test]
+    fn different_seeds_give_different_strips() {
+        for mode in [EGlitchRandomMode::Repeat, EGlitchRandomMode::Sequence] {
+            let a = items_at(&mut glitch(7, mode), 12_345.);
+            let b = items_at(&mut glitch(8, mode), 12_345.);
+            assert_ne!(a, b);
+        }
+    }
+
+    #[test]
+    fn seeking_back_replays_the_cycle() {
+        let mut item = glitch(7, EGlitchRandomMode::Sequence);
+        let first = items_at(&mut item, 1_000.);
+        items_at(&mut item, 60_000.);
+        assert_eq!(items_at(&mut item, 1_000.), first);
+    }
+
+    #[test]
+    fn repeat_reuses_strips_across_cycles() {
+        let lifetime = HorizonGlitch::default().lifetime as f64;
+        let mut repeat = glitch(7, EGlitchRandomMode::Repeat);
+        assert_eq!(items_at(&mut repeat, 1_000.), items_at(&mut repeat, 1_000. + lifetime));
+
+        let mut sequence = glitch(7, EGlitchRandomMode::Sequence);
+        assert_ne!(items_at(&mut sequence, 1_000.), items_at(&mut sequence, 1_000. + lifetime));
+    }
+
+    #[test]
+    fn default_is_deterministic() {
+        assert_eq!(HorizonGlitch::default().random_mode, EGlitchRandomMode::Repeat);
+        assert_eq!(items_at(&mut HorizonGlitch::default(), 2_500.), items_at(&mut HorizonGlitch::default(), 2_500.));
+    }
+}
@@ -28,17 +28,16 @@ impl HSB {
     }
     ///
     /// F32x4
-    pub fn collect(item: Option<&Self>, list: &mut Vec<f32>) {
+    pub fn uniform(item: Option<&Self>) -> [f32; 4] {
         if let Some(item) = item {
-            list.push(1.);
-            list.push(item.hue as f32 / 360.);
-            list.push(item.saturate as f32 / 100.);
-            list.push(item.brightness as f32 / 100.);
+            [
+                1.,
+                item.hue as f32 / 360.,
+                item.saturate as f32 / 100.,
+                item.brightness as f32 / 100.,
+            ]
         } else {
-            list.push(0.);
-            list.push(0.);
-            list.push(0.);
-            list.push(0.);
+            [0.; 4]
         }
     }
 }
\ No newline at end of file
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use crate::prelude::{PostprocessTexture, ImageEffectUniformBuffer, SingleImageEffectResource};
+use super::uniform::*;
 
 #[derive(Clone, Copy, Debug)]
 pub enum EMaskMode {
@@ -51,33 +52,25 @@ impl super::TEffectForBuffer for ImageMaskRenderer {
         src_premultiplied: bool,
         dst_premultiply: bool,
     ) -> &pi_render::rhi::buffer::Buffer {
-        let mut temp = vec![];
-        geo_matrix.iter().for_each(|v| { temp.push(*v) });
-        temp.push(tex_matrix.0);
-        temp.push(tex_matrix.1);
-        temp.push(tex_matrix.2);
-        temp.push(tex_matrix.3);
+        let mask_mode = match self.param.mode {
+            EMaskMode::Clip => 0.,
+            EMaskMode::ClipAndMultiplyAlpha => 1.,
+        };
+        let data = ImageMaskUniform {
+            vertex_matrix: mat4(geo_matrix),
+            diffuse_mat: vec4(tex_matrix),
+            mask_mat: vec4(self.param.image.get_tilloff()),
+            mask_factor: self.param.factor,
+            mask_mode,
+            depth,
+            alpha,
+            src_preimultiplied: flag(src_premultiplied),
+            dst_preimultiply: flag(dst_premultiply),
+            wasm0: 0.,
+            wasm1: 0.,
+        };
 
-        let mask_matrix = self.param.image.get_tilloff();
-        temp.push(mask_matrix.0);
-        temp.push(mask_matrix.1);
-        temp.push(mask_matrix.2);
-        temp.push(mask_matrix.3);
-
-        temp.push(self.param.factor);
-        match self.param.mode {
-            EMaskMode::Clip => temp.push(0.),
-            EMaskMode::ClipAndMultiplyAlpha => temp.push(1.),
-        }
-        temp.push(depth);
-        temp.push(alpha);
-        
-        if src_premultiplied { temp.push(1.); } else { temp.push(0.); }
-        if dst_premultiply { temp.push(1.); } else { temp.push(0.); }
-        temp.push(0.);
-        temp.push(0.);
-
-        queue.write_buffer(self.uniform.buffer(), 0, bytemuck::cast_slice(&temp));
+        queue.write_buffer(self.uniform.buffer(), 0, bytemuck::bytes_of(&data));
         self.uniform.buffer()
     }
 }
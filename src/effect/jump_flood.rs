@@ -0,0 +1,113 @@
+use std::sync::Arc;
+
+use crate::prelude::{ImageEffectUniformBuffer, SingleImageEffectResource};
+use super::uniform::*;
+
+/// 跳跃泛洪距离场 - 由源内容 alpha 生成有向距离场
+/// * 种子为形状边缘像素, 经 log2(N) 步跳跃传播最近种子, N 取源尺寸与 spread 中较小者向上取 2 的幂
+/// * 输出 rgb 为编码后的距离, a 为 1: 边缘处 0.5, 形状内大于 0.5; 有向距离 = (0.5 - v) * 2 * spread, 形状外为正
+/// * 中间结果以 16 位整数坐标写入 rgba8 通道, 目标格式需为非 sRGB 格式
+#[derive(Clone, Copy, Debug)]
+pub struct JumpFloodSdf {
+    /// 距离场范围 - 像素, 超出范围的距离截断
+    pub spread: f32,
+    /// alpha 不小于该值视为形状内
+    pub threshold: f32,
+}
+
+impl Default for JumpFloodSdf {
+    fn default() -> Self {
+        Self { spread: 16., threshold: 0.5 }
+    }
+}
+
+impl JumpFloodSdf {
+    /// 跳跃步数上限 - 对应 16384 像素
+    pub const MAX_JUMPS: u32 = 14;
+    pub fn is_enabled(&self) -> bool {
+        self.spread >= 1.
+    }
+    /// 跳跃步数 - 步长依次为 N/2, N/4, ..., 1
+    pub fn jump_count(&self, size: (u32, u32)) -> u32 {
+        let range = self.spread.max(0.).min(16384.).ceil() as u32 + 1;
+        let n = size.0.max(size.1).min(range).max(1).next_power_of_two();
+        n.trailing_zeros().min(Self::MAX_JUMPS)
+    }
+    /// 采样范围 - 像素
+    pub fn reach(&self, _size: (u32, u32)) -> Option<(u32, u32)> {
+        let spread = self.spread.max(0.).ceil() as u32;
+        Some((spread, spread))
+    }
+}
+
+/// 距离场的渲染步骤
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum EJumpFloodPass {
+    /// 标记边缘像素为种子
+    Seed = 0,
+    /// 以步长查找 3x3 邻域中更近的种子
+    Jump = 1,
+    /// 由最近种子计算有向距离并编码输出
+    Resolve = 2,
+}
+
+pub struct JumpFloodRenderer {
+    pub(crate) param: JumpFloodSdf,
+    /// 各步骤的 Uniform - 种子, 各跳跃步, 输出依次排列
+    pub(crate) uniforms: Vec<Arc<ImageEffectUniformBuffer>>,
+}
+impl JumpFloodRenderer {
+    pub fn new(param: &JumpFloodSdf, resource: &SingleImageEffectResource) -> Self {
+        let mut result = Self { param: param.clone(), uniforms: vec![] };
+        result.update(param, resource);
+        result
+    }
+    /// Uniform 数量按 spread 对应的最大步数增长
+    pub fn update(&mut self, param: &JumpFloodSdf, resource: &SingleImageEffectResource) {
+        self.param = param.clone();
+        let count = param.jump_count((u32::MAX, u32::MAX)) as usize + 2;
+        while self.uniforms.len() < count {
+            self.uniforms.push(resource.uniform_buffer());
+        }
+    }
+    /// * `index`
+    ///   * 步骤序号 - 种子为 0, 跳跃步依次递增, 输出为最后
+    /// * `jump`
+    ///   * 跳跃步长 - 像素
+    /// * `source_mat`
+    ///   * 输出步骤中源内容纹理的使用区域
+    pub(crate) fn buffer(&self,
+        index: usize,
+        pass: EJumpFloodPass,
+        jump: u32,
+        geo_matrix: &[f32],
+        tex_matrix: (f32, f32, f32, f32),
+        source_mat: (f32, f32, f32, f32),
+        alpha: f32, depth: f32,
+        queue: &pi_render::rhi::RenderQueue,
+        size: (u32, u32),
+        src_premultiplied: bool,
+        dst_premultiply: bool,
+    ) -> &pi_render::rhi::buffer::Buffer {
+        let data = JumpFloodUniform {
+            vertex_matrix: mat4(geo_matrix),
+            diffuse_mat: vec4(tex_matrix),
+            source_mat: vec4(source_mat),
+            size: [size.0 as f32, size.1 as f32],
+            jump: jump as f32,
+            stage: pass as u32 as f32,
+            spread: self.param.spread,
+            threshold: self.param.threshold,
+            depth,
+            alpha,
+            src_preimultiplied: flag(src_premultiplied),
+            dst_preimultiply: flag(dst_premultiply),
+            _wasm_0: 0.,
+            _wasm_1: 0.,
+        };
+
+        let uniform = &self.uniforms[index];
+        queue.write_buffer(uniform.buffer(), 0, bytemuck::bytes_of(&data));
+        uniform.buffer()
+    }
+}
@@ -23,6 +23,18 @@ mod horizon_glitch;
 mod color_effect;
 mod image_mask;
 mod clip_sdf;
+mod depth;
+mod depth_fog;
+mod blur_bokeh_depth;
+mod drop_shadow;
+mod glow;
+mod outline;
+mod jump_flood;
+mod chromatic_aberration;
+mod film_grain;
+mod pixelate;
+mod uniform_size;
+mod uniform;
 
 pub use alpha::*;
 pub use area_mask::*;
@@ -45,6 +57,21 @@ pub use horizon_glitch::*;
 pub use color_effect::*;
 pub use image_mask::*;
 pub use clip_sdf::*;
+pub use depth::*;
+pub use depth_fog::*;
+pub use blur_bokeh_depth::*;
+pub use drop_shadow::*;
+pub use glow::*;
+pub use outline::*;
+pub use jump_flood::*;
+pub use chromatic_aberration::*;
+pub use film_grain::*;
+pub use pixelate::*;
+pub use uniform_size::*;
+pub use uniform::{
+    BlurBokehDepthUniform, BlurCenterUniform, BlurDirectUniform, BlurDualUniform, BlurGaussUniform, ChromaticAberrationUniform, ClipSdfUniform, ColorEffectUniform, CopyUniform, DepthFogUniform,
+    DropShadowUniform, FilmGrainUniform, FilterBrightnessUniform, GlowUniform, HorizonGlitchUniform, ImageMaskUniform, JumpFloodUniform, OutlineUniform, PixelateUniform, RadialWaveUniform, SobelUniform,
+};
 
 pub trait TEffectForBuffer {
     fn buffer(
@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use crate::prelude::{ImageEffectUniformBuffer, SingleImageEffectResource};
+use super::uniform::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EOutlinePosition {
+    /// 描边在形状外 - 绘制在源内容之下
+    Outside,
+    /// 描边在形状内 - 覆盖源内容边缘
+    Inside,
+    /// 描边以形状边缘居中
+    Center,
+}
+
+/// 描边 - 沿源内容 alpha 边缘绘制指定宽度的描边
+/// * 由距离场计算, 宽度为数十像素时仍保持平滑
+#[derive(Clone, Copy, Debug)]
+pub struct Outline {
+    /// 描边宽度 - 像素
+    pub width: f32,
+    /// 描边颜色
+    pub color: (u8, u8, u8, u8),
+    pub position: EOutlinePosition,
+}
+
+impl Default for Outline {
+    fn default() -> Self {
+        Self { width: 0., color: (0, 0, 0, 255), position: EOutlinePosition::Outside }
+    }
+}
+
+impl Outline {
+    /// alpha 不小于该值视为形状内
+    pub const ALPHA_THRESHOLD: f32 = 0.5;
+    /// 搜索半径上限 - 像素, 与着色器循环范围一致
+    pub const MAX_RADIUS: u32 = 300;
+    pub fn is_enabled(&self) -> bool {
+        self.width > 0. && self.color.3 > 0
+    }
+    /// 距离场的搜索半径 - 像素
+    pub fn search_radius(&self) -> u32 {
+        let reach = match self.position {
+            EOutlinePosition::Center => self.width * 0.5,
+            _ => self.width,
+        };
+        (reach.ceil() as u32 + 1).min(Self::MAX_RADIUS)
+    }
+    /// 采样范围 - 像素
+    pub fn reach(&self, _size: (u32, u32)) -> Option<(u32, u32)> {
+        let radius = self.search_radius();
+        Some((radius, radius))
+    }
+}
+
+/// 描边的渲染步骤
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum EOutlinePass {
+    /// 每行到形状内, 外最近像素的水平距离
+    Horizontal = 0,
+    /// 合并垂直方向得到二维距离
+    Vertical = 1,
+    /// 按距离绘制描边并与源内容合成
+    Compose = 2,
+}
+
+pub struct OutlineRenderer {
+    pub(crate) param: Outline,
+    /// 各步骤的 Uniform - 按 EOutlinePass 索引
+    pub(crate) uniforms: [Arc<ImageEffectUniformBuffer>; 3],
+}
+impl OutlineRenderer {
+    pub fn new(param: &Outline, resource: &SingleImageEffectResource) -> Self {
+        Self {
+            param: param.clone(),
+            uniforms: [resource.uniform_buffer(), resource.uniform_buffer(), resource.uniform_buffer()],
+        }
+    }
+    /// * `second_mat`
+    ///   * 合成步骤中距离纹理的使用区域
+    pub(crate) fn buffer(&self,
+        pass: EOutlinePass,
+        geo_matrix: &[f32],
+        tex_matrix: (f32, f32, f32, f32),
+        second_mat: (f32, f32, f32, f32),
+        alpha: f32, depth: f32,
+        queue: &pi_render::rhi::RenderQueue,
+        src_size: (u32, u32),
+        src_premultiplied: bool,
+        dst_premultiply: bool,
+    ) -> &pi_render::rhi::buffer::Buffer {
+        let placement = match self.param.position {
+            EOutlinePosition::Outside => 0.,
+            EOutlinePosition::Inside => 1.,
+            EOutlinePosition::Center => 2.,
+        };
+        let data = OutlineUniform {
+            vertex_matrix: mat4(geo_matrix),
+            diffuse_mat: vec4(tex_matrix),
+            second_mat: vec4(second_mat),
+            color: [
+                self.param.color.0 as f32 / 255.0,
+                self.param.color.1 as f32 / 255.0,
+                self.param.color.2 as f32 / 255.0,
+                self.param.color.3 as f32 / 255.0,
+            ],
+            texel: [1.0 / src_size.0 as f32, 1.0 / src_size.1 as f32],
+            radius: self.param.search_radius() as f32,
+            width: self.param.width,
+            placement,
+            stage: pass as u32 as f32,
+            depth,
+            alpha,
+            threshold: Outline::ALPHA_THRESHOLD,
+            src_preimultiplied: flag(src_premultiplied),
+            dst_preimultiply: flag(dst_premultiply),
+            _wasm_0: 0.,
+        };
+
+        let uniform = &self.uniforms[pass as usize];
+        queue.write_buffer(uniform.buffer(), 0, bytemuck::bytes_of(&data));
+        uniform.buffer()
+    }
+}
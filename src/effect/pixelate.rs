@@ -0,0 +1,90 @@
+use std::sync::Arc;
+
+use crate::prelude::{ImageEffectUniformBuffer, SingleImageEffectResource};
+use super::uniform::*;
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EPixelateShape {
+    /// 矩形单元
+    Square,
+    /// 六边形单元 - 宽 block_x, 高 block_y, 相邻行错开半个单元
+    Hexagon,
+    /// 圆形单元 - 矩形单元内切圆, 圆外透明
+    Circle,
+}
+
+/// 像素化 - 马赛克
+/// * 单元网格从源内容使用区域左上角开始, 采样不超出使用区域
+#[derive(Clone, Copy, Debug)]
+pub struct Pixelate {
+    /// 单元宽度 - 像素
+    pub block_x: f32,
+    /// 单元高度 - 像素
+    pub block_y: f32,
+    pub shape: EPixelateShape,
+    /// 采样单元内平均颜色, 否则采样单元中心
+    pub average: bool,
+}
+
+impl Default for Pixelate {
+    fn default() -> Self {
+        Self { block_x: 1., block_y: 1., shape: EPixelateShape::Square, average: false }
+    }
+}
+
+impl Pixelate {
+    pub fn is_enabled(&self) -> bool {
+        self.block_x > 1. || self.block_y > 1.
+    }
+    /// 采样范围 - 像素, 单元内任一点变化影响整个单元
+    pub fn reach(&self, _size: (u32, u32)) -> Option<(u32, u32)> {
+        Some((self.block_x.max(1.).ceil() as u32, self.block_y.max(1.).ceil() as u32))
+    }
+}
+pub struct PixelateRenderer {
+    pub(crate) param: Pixelate,
+    pub(crate) uniform: Arc<ImageEffectUniformBuffer>,
+}
+impl PixelateRenderer {
+    pub fn new(param: &Pixelate, resources: &SingleImageEffectResource) -> Self {
+        Self { param: param.clone(), uniform: resources.uniform_buffer() }
+    }
+}
+impl super::TEffectForBuffer for PixelateRenderer {
+    fn buffer(&self, 
+        _: u64,
+        geo_matrix: &[f32],
+        tex_matrix: (f32, f32, f32, f32),
+        alpha: f32, depth: f32,
+        _device: &pi_render::rhi::device::RenderDevice,
+        queue: &pi_render::rhi::RenderQueue,
+        src_size: (u32, u32),
+        _: (u32, u32),
+        src_premultiplied: bool,
+        dst_premultiply: bool,
+    ) -> &pi_render::rhi::buffer::Buffer {
+        let data = PixelateUniform {
+            vertex_matrix: mat4(geo_matrix),
+            diffuse_mat: vec4(tex_matrix),
+            texel: [1.0 / src_size.0 as f32, 1.0 / src_size.1 as f32],
+            block_x: self.param.block_x.max(1.),
+            block_y: self.param.block_y.max(1.),
+            shape: match self.param.shape {
+                EPixelateShape::Square => 0.,
+                EPixelateShape::Hexagon => 1.,
+                EPixelateShape::Circle => 2.,
+            },
+            average: flag(self.param.average),
+            depth,
+            alpha,
+            src_preimultiplied: flag(src_premultiplied),
+            dst_preimultiply: flag(dst_premultiply),
+            _wasm_0: 0.,
+            _wasm_1: 0.,
+        };
+
+        queue.write_buffer(self.uniform.buffer(), 0, bytemuck::bytes_of(&data));
+        self.uniform.buffer()
+    }
+}
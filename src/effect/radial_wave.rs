@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use crate::prelude::{ImageEffectUniformBuffer, SingleImageEffectResource};
+use super::uniform::*;
 
 
 #[derive(Clone, Copy, Debug)]
@@ -28,6 +29,11 @@ impl RadialWave {
             (self.start - self.center_x).abs() < 3.
             && (self.start - self.center_y).abs() < 3.
         )
+    }
+    /// 采样范围 - 像素, 扭曲偏移最大为 weight 倍尺寸
+    pub fn reach(&self, size: (u32, u32)) -> Option<(u32, u32)> {
+        let weight = self.weight.abs();
+        Some(((weight * size.0 as f32).ceil() as u32, (weight * size.1 as f32).ceil() as u32))
     }  
 }
 pub struct RadialWaveRenderer {
@@ -52,35 +58,28 @@ impl super::TEffectForBuffer for RadialWaveRenderer {
         src_premultiplied: bool,
         dst_premultiply: bool,
     ) -> &pi_render::rhi::buffer::Buffer {
-        let mut temp = vec![
-
-        ];
-        geo_matrix.iter().for_each(|v| { temp.push(*v) });
-        temp.push(tex_matrix.0);
-        temp.push(tex_matrix.1);
-        temp.push(tex_matrix.2);
-        temp.push(tex_matrix.3);
-
         let mut aspect_ratio = 1.0;
         if self.param.aspect_ratio {
             aspect_ratio = dst_size.1 as f32 / dst_size.0 as f32;
         }
-        temp.push(self.param.center_x);
-        temp.push(self.param.center_y);
-        temp.push(aspect_ratio);
-        temp.push(self.param.start);
-        
-        temp.push(self.param.end);
-        temp.push(self.param.cycle as f32);
-        temp.push(self.param.weight);
-        temp.push(depth);
-
-        temp.push(alpha);
-        if src_premultiplied { temp.push(1.); } else { temp.push(0.); }
-        if dst_premultiply { temp.push(1.); } else { temp.push(0.); }
-        temp.push(0.);
+        let data = RadialWaveUniform {
+            vertex_matrix: mat4(geo_matrix),
+            diffuse_mat: vec4(tex_matrix),
+            centerx: self.param.center_x,
+            centery: self.param.center_y,
+            aspect_ratio,
+            start: self.param.start,
+            end: self.param.end,
+            cycle: self.param.cycle as f32,
+            weight: self.param.weight,
+            depth,
+            alpha,
+            src_preimultiplied: flag(src_premultiplied),
+            dst_preimultiply: flag(dst_premultiply),
+            wasm2: 0.,
+        };
 
-        queue.write_buffer(self.uniform.buffer(), 0, bytemuck::cast_slice(&temp));
+        queue.write_buffer(self.uniform.buffer(), 0, bytemuck::bytes_of(&data));
         self.uniform.buffer()
     }
 }
\ No newline at end of file
@@ -0,0 +1,545 @@
+//! 各效果着色器 Uniform 块对应的 std140 结构
+//! * 成员顺序与命名对应 src/shaders 中的 Uniform 块
+//! * 大小与成员偏移在编译期与 build.rs 从着色器生成的 uniform_size 比对
+
+use bytemuck::{Pod, Zeroable};
+
+use super::uniform_size::*;
+
+/// 编译期检查 std140 布局
+/// * 结构大小 = 着色器 Uniform 块大小, 且为 16 的倍数
+/// * 每个字段都需列出, 偏移与着色器中同名成员一致 - 名称比较忽略大小写与 '_'
+/// * 字段对应多个着色器成员时, 以 `字段 = "首个成员"` 指定
+macro_rules! check_std140 {
+    (@member $field:ident) => { stringify!($field) };
+    (@member $field:ident $member:literal) => { $member };
+    ($ty:ty, $floats:expr, $layout:expr, [$($field:ident $(= $member:literal)?),*]) => {
+        const _: () = {
+            assert!(std::mem::size_of::<$ty>() == $floats * 4);
+            assert!(std::mem::size_of::<$ty>() % 16 == 0);
+            let mut size = 0;
+            $(
+                assert!(
+                    matches!(member_offset($layout, check_std140!(@member $field $($member)?)), Some(offset) if offset == std::mem::offset_of!($ty, $field)),
+                    concat!(stringify!($ty), "::", stringify!($field), " does not match the shader uniform member"),
+                );
+                size += field_size(|v: &$ty| &v.$field);
+            )*
+            assert!(size == std::mem::size_of::<$ty>(), concat!(stringify!($ty), ": fields missing from check_std140"));
+        };
+    };
+}
+
+/// 着色器 Uniform 块中与 name 同名成员的偏移
+const fn member_offset(layout: &[(&str, usize)], name: &str) -> Option<usize> {
+    let mut i = 0;
+    while i < layout.len() {
+        if same_name(layout[i].0, name) {
+            return Some(layout[i].1);
+        }
+        i += 1;
+    }
+    None
+}
+/// 忽略大小写与 '_' 比较 - Rust 字段为 snake_case, 着色器成员多为 camelCase
+const fn same_name(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let (mut i, mut j) = (0, 0);
+    loop {
+        while i < a.len() && a[i] == b'_' { i += 1; }
+        while j < b.len() && b[j] == b'_' { j += 1; }
+        if i == a.len() || j == b.len() {
+            return i == a.len() && j == b.len();
+        }
+        if a[i].to_ascii_lowercase() != b[j].to_ascii_lowercase() {
+            return false;
+        }
+        i += 1;
+        j += 1;
+    }
+}
+const fn field_size<T, F>(_: fn(&T) -> &F) -> usize {
+    std::mem::size_of::<F>()
+}
+
+/// 几何矩阵 - 不足 16 个数时补 0
+pub(crate) fn mat4(geo_matrix: &[f32]) -> [f32; 16] {
+    let mut result = [0.; 16];
+    let len = geo_matrix.len().min(16);
+    result[0..len].copy_from_slice(&geo_matrix[0..len]);
+    result
+}
+pub(crate) fn vec4(val: (f32, f32, f32, f32)) -> [f32; 4] {
+    [val.0, val.1, val.2, val.3]
+}
+pub(crate) fn flag(val: bool) -> f32 {
+    if val { 1. } else { 0. }
+}
+
+/// blur_bokeh / blur_radial
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+pub struct BlurCenterUniform {
+    pub vertex_matrix: [f32; 16],
+    pub diffuse_mat: [f32; 4],
+
+    pub center: [f32; 2],
+    pub offset: f32,
+    pub iteration: f32,
+
+    pub start: f32,
+    pub fade: f32,
+    pub depth: f32,
+    pub alpha: f32,
+
+    pub src_preimultiplied: f32,
+    pub dst_preimultiply: f32,
+    pub _wasm_0: f32,
+    pub _wasm_1: f32,
+}
+check_std140!(BlurCenterUniform, UNIFORM_FLOATS_BLUR_BOKEH, UNIFORM_LAYOUT_BLUR_BOKEH, [vertex_matrix, diffuse_mat, center, offset, iteration, start, fade, depth, alpha, src_preimultiplied, dst_preimultiply, _wasm_0, _wasm_1]);
+check_std140!(BlurCenterUniform, UNIFORM_FLOATS_BLUR_RADIAL, UNIFORM_LAYOUT_BLUR_RADIAL, [vertex_matrix, diffuse_mat, center, offset, iteration, start, fade, depth, alpha, src_preimultiplied, dst_preimultiply, _wasm_0, _wasm_1]);
+
+/// blur_bokeh_depth
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+pub struct BlurBokehDepthUniform {
+    pub vertex_matrix: [f32; 16],
+    pub diffuse_mat: [f32; 4],
+
+    pub depth_mat: [f32; 4],
+
+    pub offset: f32,
+    pub iteration: f32,
+    pub near: f32,
+    pub far: f32,
+
+    pub focus: f32,
+    pub range: f32,
+    pub reversed: f32,
+    pub depth: f32,
+
+    pub alpha: f32,
+    pub src_preimultiplied: f32,
+    pub dst_preimultiply: f32,
+    pub _wasm_0: f32,
+}
+check_std140!(BlurBokehDepthUniform, UNIFORM_FLOATS_BLUR_BOKEH_DEPTH, UNIFORM_LAYOUT_BLUR_BOKEH_DEPTH, [vertex_matrix, diffuse_mat, depth_mat, offset, iteration, near, far, focus, range, reversed, depth, alpha, src_preimultiplied, dst_preimultiply, _wasm_0]);
+
+/// blur_direct
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+pub struct BlurDirectUniform {
+    pub vertex_matrix: [f32; 16],
+    pub diffuse_mat: [f32; 4],
+
+    pub direct: [f32; 2],
+    pub offset: f32,
+    pub iteration: f32,
+
+    pub depth: f32,
+    pub alpha: f32,
+    pub src_preimultiplied: f32,
+    pub dst_preimultiply: f32,
+}
+check_std140!(BlurDirectUniform, UNIFORM_FLOATS_BLUR_DIRECT, UNIFORM_LAYOUT_BLUR_DIRECT, [vertex_matrix, diffuse_mat, direct, offset, iteration, depth, alpha, src_preimultiplied, dst_preimultiply]);
+
+/// blur_dual
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+pub struct BlurDualUniform {
+    pub vertex_matrix: [f32; 16],
+    pub diffuse_mat: [f32; 4],
+
+    pub offset: [f32; 2],
+    pub intensity: f32,
+    pub dualmode: f32,
+
+    pub depth: f32,
+    pub alpha: f32,
+    pub src_preimultiplied: f32,
+    pub dst_preimultiply: f32,
+}
+check_std140!(BlurDualUniform, UNIFORM_FLOATS_BLUR_DUAL, UNIFORM_LAYOUT_BLUR_DUAL, [vertex_matrix, diffuse_mat, offset, intensity, dualmode, depth, alpha, src_preimultiplied, dst_preimultiply]);
+
+/// blur_gauss
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+pub struct BlurGaussUniform {
+    pub vertex_matrix: [f32; 16],
+    pub diffuse_mat: [f32; 4],
+
+    pub texture_size: [f32; 2],
+    pub blur_radius: f32,
+    pub horizontal: f32,
+
+    pub depth: f32,
+    pub alpha: f32,
+    pub src_preimultiplied: f32,
+    pub dst_preimultiply: f32,
+}
+check_std140!(BlurGaussUniform, UNIFORM_FLOATS_BLUR_GAUSS, UNIFORM_LAYOUT_BLUR_GAUSS, [vertex_matrix, diffuse_mat, texture_size, blur_radius, horizontal, depth, alpha, src_preimultiplied, dst_preimultiply]);
+
+/// chromatic_aberration
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+pub struct ChromaticAberrationUniform {
+    pub vertex_matrix: [f32; 16],
+    pub diffuse_mat: [f32; 4],
+
+    pub centerx: f32,
+    pub centery: f32,
+    pub direct_x: f32,
+    pub direct_y: f32,
+
+    pub texel: [f32; 2],
+    pub strength: f32,
+    pub falloff: f32,
+
+    pub mode: f32,
+    pub depth: f32,
+    pub alpha: f32,
+    pub src_preimultiplied: f32,
+
+    pub dst_preimultiply: f32,
+    pub _wasm_0: f32,
+    pub _wasm_1: f32,
+    pub _wasm_2: f32,
+}
+check_std140!(ChromaticAberrationUniform, UNIFORM_FLOATS_CHROMATIC_ABERRATION, UNIFORM_LAYOUT_CHROMATIC_ABERRATION, [vertex_matrix, diffuse_mat, centerx, centery, direct_x, direct_y, texel, strength, falloff, mode, depth, alpha, src_preimultiplied, dst_preimultiply, _wasm_0, _wasm_1, _wasm_2]);
+
+/// clip_sdf
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+pub struct ClipSdfUniform {
+    pub vertex_matrix: [f32; 16],
+    pub diffuse_mat: [f32; 4],
+
+    /// clipSdf0 - clipSdf3
+    pub clip_sdf: [f32; 16],
+
+    pub mode: f32,
+    pub depth: f32,
+    pub alpha: f32,
+    pub src_preimultiplied: f32,
+
+    pub dst_preimultiply: f32,
+    /// 形状因子低于该值时丢弃片元, 0 为不丢弃
+    pub cutoff: f32,
+    pub _wasm_1: f32,
+    pub _wasm_2: f32,
+}
+check_std140!(ClipSdfUniform, UNIFORM_FLOATS_CLIP_SDF, UNIFORM_LAYOUT_CLIP_SDF, [vertex_matrix, diffuse_mat, clip_sdf = "clipSdf0", mode, depth, alpha, src_preimultiplied, dst_preimultiply, cutoff, _wasm_1, _wasm_2]);
+
+/// color_effect - 每个子效果以 flag 开头, flag 为 0 时该段无效
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+pub struct ColorEffectUniform {
+    pub vertex_matrix: [f32; 16],
+    pub diffuse_mat: [f32; 4],
+
+    pub color_balance: [f32; 4],
+    pub hsb: [f32; 4],
+    pub color_scale: [f32; 6],
+    pub vignette: [f32; 7],
+    pub color_filter: [f32; 4],
+
+    pub depth: f32,
+    pub alpha: f32,
+    pub src_preimultiplied: f32,
+
+    pub dst_preimultiply: f32,
+    pub _wasm_0: f32,
+    pub _wasm_1: f32,
+    pub _wasm_2: f32,
+}
+check_std140!(ColorEffectUniform, UNIFORM_FLOATS_COLOR_EFFECT, UNIFORM_LAYOUT_COLOR_EFFECT, [vertex_matrix, diffuse_mat, color_balance = "flag1", hsb = "flag2", color_scale = "flag3", vignette = "flag4", color_filter = "flag5", depth, alpha, src_preimultiplied, dst_preimultiply, _wasm_0, _wasm_1, _wasm_2]);
+
+/// copy
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+pub struct CopyUniform {
+    pub vertex_matrix: [f32; 16],
+    pub diffuse_mat: [f32; 4],
+
+    pub intensity: f32,
+    pub polygon_n: f32,
+    pub radius: f32,
+    pub rotate: f32,
+
+    pub bg_color: [f32; 4],
+
+    pub depth: f32,
+    pub alpha: f32,
+    pub src_preimultiplied: f32,
+    pub dst_preimultiply: f32,
+}
+check_std140!(CopyUniform, UNIFORM_FLOATS_COPY, UNIFORM_LAYOUT_COPY, [vertex_matrix, diffuse_mat, intensity, polygon_n, radius, rotate, bg_color = "bgColorR", depth, alpha, src_preimultiplied, dst_preimultiply]);
+
+/// depth_fog
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+pub struct DepthFogUniform {
+    pub vertex_matrix: [f32; 16],
+    pub diffuse_mat: [f32; 4],
+
+    pub depth_mat: [f32; 4],
+    pub fog_color: [f32; 4],
+
+    pub near: f32,
+    pub far: f32,
+    pub fog_start: f32,
+    pub fog_end: f32,
+
+    pub density: f32,
+    pub mode: f32,
+    pub reversed: f32,
+    pub depth: f32,
+
+    pub alpha: f32,
+    pub src_preimultiplied: f32,
+    pub dst_preimultiply: f32,
+    pub _wasm_0: f32,
+}
+check_std140!(DepthFogUniform, UNIFORM_FLOATS_DEPTH_FOG, UNIFORM_LAYOUT_DEPTH_FOG, [vertex_matrix, diffuse_mat, depth_mat, fog_color, near, far, fog_start, fog_end, density, mode, reversed, depth, alpha, src_preimultiplied, dst_preimultiply, _wasm_0]);
+
+/// drop_shadow
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+pub struct DropShadowUniform {
+    pub vertex_matrix: [f32; 16],
+    pub diffuse_mat: [f32; 4],
+
+    pub shadow_mat: [f32; 4],
+    pub shadow_color: [f32; 4],
+
+    pub offset: [f32; 2],
+    pub depth: f32,
+    pub alpha: f32,
+
+    pub src_preimultiplied: f32,
+    pub dst_preimultiply: f32,
+    pub _wasm_0: f32,
+    pub _wasm_1: f32,
+}
+check_std140!(DropShadowUniform, UNIFORM_FLOATS_DROP_SHADOW, UNIFORM_LAYOUT_DROP_SHADOW, [vertex_matrix, diffuse_mat, shadow_mat, shadow_color, offset, depth, alpha, src_preimultiplied, dst_preimultiply, _wasm_0, _wasm_1]);
+
+/// film_grain
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+pub struct FilmGrainUniform {
+    pub vertex_matrix: [f32; 16],
+    pub diffuse_mat: [f32; 4],
+
+    pub texel: [f32; 2],
+    pub grain_size: f32,
+    pub intensity: f32,
+
+    pub luminance_response: f32,
+    pub monochrome: f32,
+    pub frame: f32,
+    pub seed: f32,
+
+    pub depth: f32,
+    pub alpha: f32,
+    pub src_preimultiplied: f32,
+    pub dst_preimultiply: f32,
+}
+check_std140!(FilmGrainUniform, UNIFORM_FLOATS_FILM_GRAIN, UNIFORM_LAYOUT_FILM_GRAIN, [vertex_matrix, diffuse_mat, texel, grain_size, intensity, luminance_response, monochrome, frame, seed, depth, alpha, src_preimultiplied, dst_preimultiply]);
+
+/// filter_brightness
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+pub struct FilterBrightnessUniform {
+    pub vertex_matrix: [f32; 16],
+    pub diffuse_mat: [f32; 4],
+
+    pub threshold: [f32; 4],
+
+    pub depth: f32,
+    pub alpha: f32,
+    pub src_preimultiplied: f32,
+    pub dst_preimultiply: f32,
+}
+check_std140!(FilterBrightnessUniform, UNIFORM_FLOATS_FILTER_BRIGHTNESS, UNIFORM_LAYOUT_FILTER_BRIGHTNESS, [vertex_matrix, diffuse_mat, threshold, depth, alpha, src_preimultiplied, dst_preimultiply]);
+
+/// glow
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+pub struct GlowUniform {
+    pub vertex_matrix: [f32; 16],
+    pub diffuse_mat: [f32; 4],
+
+    pub glow_mat: [f32; 4],
+    pub glow_color: [f32; 4],
+
+    pub strength: f32,
+    pub mode: f32,
+    pub depth: f32,
+    pub alpha: f32,
+
+    pub src_preimultiplied: f32,
+    pub dst_preimultiply: f32,
+    pub _wasm_0: f32,
+    pub _wasm_1: f32,
+}
+check_std140!(GlowUniform, UNIFORM_FLOATS_GLOW, UNIFORM_LAYOUT_GLOW, [vertex_matrix, diffuse_mat, glow_mat, glow_color, strength, mode, depth, alpha, src_preimultiplied, dst_preimultiply, _wasm_0, _wasm_1]);
+
+/// horizon_glitch
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+pub struct HorizonGlitchUniform {
+    pub vertex_matrix: [f32; 16],
+    pub diffuse_mat: [f32; 4],
+
+    pub strength: f32,
+    pub fade: f32,
+    pub depth: f32,
+    pub alpha: f32,
+
+    pub src_preimultiplied: f32,
+    pub dst_preimultiply: f32,
+    pub wasm0: f32,
+    pub wasm1: f32,
+}
+check_std140!(HorizonGlitchUniform, UNIFORM_FLOATS_HORIZON_GLITCH, UNIFORM_LAYOUT_HORIZON_GLITCH, [vertex_matrix, diffuse_mat, strength, fade, depth, alpha, src_preimultiplied, dst_preimultiply, wasm0, wasm1]);
+
+/// image_mask
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+pub struct ImageMaskUniform {
+    pub vertex_matrix: [f32; 16],
+    pub diffuse_mat: [f32; 4],
+
+    pub mask_mat: [f32; 4],
+
+    pub mask_factor: f32,
+    pub mask_mode: f32,
+    pub depth: f32,
+    pub alpha: f32,
+
+    pub src_preimultiplied: f32,
+    pub dst_preimultiply: f32,
+    pub wasm0: f32,
+    pub wasm1: f32,
+}
+check_std140!(ImageMaskUniform, UNIFORM_FLOATS_IMAGE_MASK, UNIFORM_LAYOUT_IMAGE_MASK, [vertex_matrix, diffuse_mat, mask_mat, mask_factor, mask_mode, depth, alpha, src_preimultiplied, dst_preimultiply, wasm0, wasm1]);
+
+/// jump_flood
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+pub struct JumpFloodUniform {
+    pub vertex_matrix: [f32; 16],
+    pub diffuse_mat: [f32; 4],
+
+    pub source_mat: [f32; 4],
+
+    pub size: [f32; 2],
+    pub jump: f32,
+    pub stage: f32,
+
+    pub spread: f32,
+    pub threshold: f32,
+    pub depth: f32,
+    pub alpha: f32,
+
+    pub src_preimultiplied: f32,
+    pub dst_preimultiply: f32,
+    pub _wasm_0: f32,
+    pub _wasm_1: f32,
+}
+check_std140!(JumpFloodUniform, UNIFORM_FLOATS_JUMP_FLOOD, UNIFORM_LAYOUT_JUMP_FLOOD, [vertex_matrix, diffuse_mat, source_mat, size, jump, stage, spread, threshold, depth, alpha, src_preimultiplied, dst_preimultiply, _wasm_0, _wasm_1]);
+
+/// outline
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+pub struct OutlineUniform {
+    pub vertex_matrix: [f32; 16],
+    pub diffuse_mat: [f32; 4],
+
+    pub second_mat: [f32; 4],
+    pub color: [f32; 4],
+
+    pub texel: [f32; 2],
+    pub radius: f32,
+    pub width: f32,
+
+    pub placement: f32,
+    pub stage: f32,
+    pub depth: f32,
+    pub alpha: f32,
+
+    pub threshold: f32,
+    pub src_preimultiplied: f32,
+    pub dst_preimultiply: f32,
+    pub _wasm_0: f32,
+}
+check_std140!(OutlineUniform, UNIFORM_FLOATS_OUTLINE, UNIFORM_LAYOUT_OUTLINE, [vertex_matrix, diffuse_mat, second_mat, color, texel, radius, width, placement, stage, depth, alpha, threshold, src_preimultiplied, dst_preimultiply, _wasm_0]);
+
+/// pixelate
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+pub struct PixelateUniform {
+    pub vertex_matrix: [f32; 16],
+    pub diffuse_mat: [f32; 4],
+
+    pub texel: [f32; 2],
+    pub block_x: f32,
+    pub block_y: f32,
+
+    pub shape: f32,
+    pub average: f32,
+    pub depth: f32,
+    pub alpha: f32,
+
+    pub src_preimultiplied: f32,
+    pub dst_preimultiply: f32,
+    pub _wasm_0: f32,
+    pub _wasm_1: f32,
+}
+check_std140!(PixelateUniform, UNIFORM_FLOATS_PIXELATE, UNIFORM_LAYOUT_PIXELATE, [vertex_matrix, diffuse_mat, texel, block_x, block_y, shape, average, depth, alpha, src_preimultiplied, dst_preimultiply, _wasm_0, _wasm_1]);
+
+/// radial_wave
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+pub struct RadialWaveUniform {
+    pub vertex_matrix: [f32; 16],
+    pub diffuse_mat: [f32; 4],
+
+    pub centerx: f32,
+    pub centery: f32,
+    pub aspect_ratio: f32,
+    pub start: f32,
+
+    pub end: f32,
+    pub cycle: f32,
+    pub weight: f32,
+    pub depth: f32,
+
+    pub alpha: f32,
+    pub src_preimultiplied: f32,
+    pub dst_preimultiply: f32,
+    pub wasm2: f32,
+}
+check_std140!(RadialWaveUniform, UNIFORM_FLOATS_RADIAL_WAVE, UNIFORM_LAYOUT_RADIAL_WAVE, [vertex_matrix, diffuse_mat, centerx, centery, aspect_ratio, start, end, cycle, weight, depth, alpha, src_preimultiplied, dst_preimultiply, wasm2]);
+
+/// sobel
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+pub struct SobelUniform {
+    pub vertex_matrix: [f32; 16],
+    pub diffuse_mat: [f32; 4],
+
+    pub color: [f32; 4],
+    pub bg_color: [f32; 4],
+
+    pub diff_uv: [f32; 2],
+    pub clip: f32,
+    pub depth: f32,
+
+    pub alpha: f32,
+    pub src_preimultiplied: f32,
+    pub dst_preimultiply: f32,
+    pub wasm2: f32,
+}
+check_std140!(SobelUniform, UNIFORM_FLOATS_SOBEL, UNIFORM_LAYOUT_SOBEL, [vertex_matrix, diffuse_mat, color, bg_color, diff_uv = "uDiffUV", clip, depth, alpha, src_preimultiplied, dst_preimultiply, wasm2]);
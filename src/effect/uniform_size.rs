@@ -0,0 +1,5 @@
+// 各着色器 Uniform 块的 f32 个数
+// * 由 build.rs 根据 naga 解析出的 GLSL Uniform 块生成, 着色器修改后自动更新
+// * uniform.rs 中 TEffectForBuffer::buffer 写入的结构, 大小在编译期与此比对
+
+include!(concat!(env!("OUT_DIR"), "/uniform_size.rs"));
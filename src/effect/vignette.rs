@@ -35,23 +35,19 @@ impl Vignette {
         self.begin < 1.5
     }
     /// F32x7
-    pub fn collect(item: Option<&Self>, list: &mut Vec<f32>) {
+    pub fn uniform(item: Option<&Self>) -> [f32; 7] {
         if let Some(item) = item {
-            list.push(1.);
-            list.push(item.begin as f32 / 255.);
-            list.push(item.end as f32 / 255.);
-            list.push(item.scale);
-            list.push(item.r as f32 / 255.);
-            list.push(item.g as f32 / 255.);
-            list.push(item.b as f32 / 255.);
+            [
+                1.,
+                item.begin as f32 / 255.,
+                item.end as f32 / 255.,
+                item.scale,
+                item.r as f32 / 255.,
+                item.g as f32 / 255.,
+                item.b as f32 / 255.,
+            ]
         } else {
-            list.push(0.);
-            list.push(0.);
-            list.push(0.);
-            list.push(0.);
-            list.push(0.);
-            list.push(0.);
-            list.push(0.);
+            [0.; 7]
         }
     }
 }
\ No newline at end of file
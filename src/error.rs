@@ -1,5 +1,30 @@
-#[derive(Debug, Clone, Copy)]
+use crate::postprocess_flags::EPostprocessRenderType;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EPostprocessError {
     ParamMatrixSizeError,
     NotSupportTargetFormat,
-}
\ No newline at end of file
+    /// 效果资源未注册 - 对应 KEY 的效果未调用 setup
+    MissingEffectResource(&'static str),
+    /// 效果的渲染目标分配失败
+    AllocateFailed(EPostprocessRenderType),
+    /// 效果的渲染器未创建
+    MissingRenderer(EPostprocessRenderType),
+    /// 实例数据缓冲池已达上限, 或请求的区间超过单个缓冲尺寸
+    InstanceBufferExhausted,
+}
+
+/// 效果渲染失败时的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EPostprocessErrorPolicy {
+    /// calc 返回错误, draw_final 返回 None
+    Abort,
+    /// 跳过失败的效果, 以其输入作为后续效果的输入; 最终效果失败时直接拷贝其输入
+    Skip,
+}
+
+impl Default for EPostprocessErrorPolicy {
+    fn default() -> Self {
+        Self::Abort
+    }
+}
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, atomic::{AtomicUsize, Ordering}};
 
 use crossbeam::queue::SegQueue;
 use pi_assets::{mgr::AssetMgr, asset::Handle};
@@ -10,23 +10,67 @@ use pi_render::{
     },
     rhi::{
         bind_group_layout::BindGroupLayout, device::RenderDevice, buffer::Buffer,
-        sampler::{SamplerDesc, EAddressMode, EFilterMode, EAnisotropyClamp}, pipeline::RenderPipeline, bind_group::BindGroup, RenderQueue
+        sampler::{SamplerDesc, EAddressMode, EFilterMode, EAnisotropyClamp}, pipeline::RenderPipeline, bind_group::BindGroup, RenderQueue, asset::RenderRes
     },
-    asset::TAssetKeyU64,
+    asset::{TAssetKeyU64, ASSET_SIZE_FOR_UNKOWN},
     components::view::target_alloc::{SafeAtlasAllocator, TargetType}
 };
 use pi_share::Share;
 use wgpu::CommandEncoder;
 
-use crate::{material::tools::Shader, temprory_render_target::PostprocessTexture, effect::TEffectForBuffer};
+use crate::{material::tools::{Shader, EShaderLanguage, load_shader_source}, temprory_render_target::PostprocessTexture, effect::TEffectForBuffer, error::EPostprocessError};
 
 pub struct ImageEffectResource {
-    pub shader: Shader,
+    /// 未启用任何宏的着色器
+    pub shader: Arc<Shader>,
     pub sampler: Handle<SamplerRes>,
     pub sampler_nearest: Handle<SamplerRes>,
     pub bindgroup_layout: BindGroupLayout,
+    /// 着色器源码语言, 变体按同一语言编译
+    pub language: EShaderLanguage,
+    /// 按启用宏集合缓存的着色器变体
+    pub(crate) variants: Mutex<XHashMap<u32, Arc<Shader>>>,
+    /// 着色器版本 - 每次热重载递增, 参与管线 Key 计算
+    pub version: u32,
+    /// 热重载读取的 (顶点, 片元) 源码, 着色器变体从此编译; None 时使用内嵌源码
+    pub(crate) source: Option<Arc<(String, String)>>,
+    /// 以当前版本创建的管线 Key - 热重载时从管线资产管理器中移除
+    pub(crate) pipeline_keys: Mutex<Vec<u64>>,
 }
 impl ImageEffectResource {
+    pub fn new(
+        shader: Shader,
+        sampler: Handle<SamplerRes>,
+        sampler_nearest: Handle<SamplerRes>,
+        bindgroup_layout: BindGroupLayout,
+        language: EShaderLanguage,
+    ) -> Self {
+        Self {
+            shader: Arc::new(shader),
+            sampler,
+            sampler_nearest,
+            bindgroup_layout,
+            language,
+            variants: Mutex::new(XHashMap::default()),
+            version: 0,
+            source: None,
+            pipeline_keys: Mutex::new(vec![]),
+        }
+    }
+    /// 以新的着色器源码重建资源 - 采样器与布局沿用, 版本递增
+    pub fn reload(&self, shader: Shader, vs_text: String, fs_text: String) -> Self {
+        Self {
+            shader: Arc::new(shader),
+            sampler: self.sampler.clone(),
+            sampler_nearest: self.sampler_nearest.clone(),
+            bindgroup_layout: self.bindgroup_layout.clone(),
+            language: self.language,
+            variants: Mutex::new(XHashMap::default()),
+            version: self.version.wrapping_add(1),
+            source: Some(Arc::new((vs_text, fs_text))),
+            pipeline_keys: Mutex::new(vec![]),
+        }
+    }
     pub const NEAREST_FILTER: SamplerDesc  = SamplerDesc {
         address_mode_u: EAddressMode::ClampToEdge,
         address_mode_v: EAddressMode::ClampToEdge,
@@ -43,6 +87,10 @@ impl ImageEffectResource {
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub struct KeyPostprocessPipeline {
     pub key: String,
+    /// 启用的着色器宏 - 按位对应 TImageEffect::DEFINES
+    pub defines: u32,
+    /// 着色器版本 - 对应 ImageEffectResource::version, 热重载后旧管线不再命中
+    pub version: u32,
     pub depth_stencil: Option<DepthStencilState>,
     pub color_state: wgpu::ColorTargetState,
 }
@@ -62,9 +110,30 @@ impl TAssetKeyU64 for KeyPostprocessPipeline {}
 
 pub enum PostProcessDraw {
     Temp((u32, u32, u32, u32), DrawObj, ETextureViewUsage),
-    Final(DrawObj),
+    /// 局部重绘 - (视口, 裁剪区域, ..), 裁剪区域之外保留目标原有内容
+    TempScissor((u32, u32, u32, u32), (u32, u32, u32, u32), DrawObj, ETextureViewUsage),
+    /// 绘制到调用方的 RenderPass - (.., 视口 (x, y, w, h), 裁剪区域 (x, y, w, h), 模板参考值, 目标尺寸)
+    /// * 视口, 裁剪区域, 模板参考值为 None 时沿用 RenderPass 当前设置
+    /// * 设置过的状态在绘制后恢复为 RenderPass 的默认值: 视口与裁剪区域为整个目标, 模板参考值为 0;
+    ///   调用方在此之前自行设置过这些状态时, 需在之后重新设置
+    Final(DrawObj, Option<(f32, f32, f32, f32)>, Option<(u32, u32, u32, u32)>, Option<u32>, (u32, u32)),
 }
 impl PostProcessDraw {
+    /// 限制 Temp 绘制的更新区域
+    /// * `region`
+    ///   * 相对视口的区域 (x, y, w, h), 超出视口部分被裁掉
+    pub fn with_scissor(self, region: (u32, u32, u32, u32)) -> Self {
+        match self {
+            PostProcessDraw::Temp(viewport, draw, target) => {
+                let x = region.0.min(viewport.2);
+                let y = region.1.min(viewport.3);
+                let w = region.2.min(viewport.2 - x);
+                let h = region.3.min(viewport.3 - y);
+                PostProcessDraw::TempScissor(viewport, (viewport.0 + x, viewport.1 + y, w, h), draw, target)
+            },
+            _ => self,
+        }
+    }
     pub fn draw<'a>(
         &'a self,
         encoder:  Option<&mut CommandEncoder>,
@@ -72,46 +141,28 @@ impl PostProcessDraw {
     ) {
         match (self, encoder, renderpass) {
             (PostProcessDraw::Temp(viewport, draw, target), Some(encoder), None) => {
-                let (x, y, w, h) = *viewport;
-                if let Some(pipeline) = &draw.pipeline {
-                    let mut renderpass = encoder.begin_render_pass(
-                        &wgpu::RenderPassDescriptor {
-                            label: None,
-                            color_attachments: &[
-                                Some(wgpu::RenderPassColorAttachment {
-                                    view: target.view(),
-                                    resolve_target: None,
-                                    ops: wgpu::Operations {
-                                        load: wgpu::LoadOp::Load,
-                                        store: wgpu::StoreOp::Store,
-                                    }
-                                })
-                            ],
-                            depth_stencil_attachment: None,
-                            timestamp_writes: None,
-                            occlusion_query_set: None,
-                        }
-                    );
-                    // log::warn!("Viewport: {:?}", (x as f32, y as f32, w as f32, h as f32, target.key()));
-                    renderpass.set_viewport(x as f32, y as f32, w as f32, h as f32, 0., 1.);
-                    renderpass.set_scissor_rect(x, y, w, h);
-                    renderpass.set_pipeline(pipeline);
-                    draw.bindgroups.set(&mut renderpass);
-                    draw.vertices.iter().for_each(|(v, _)| {
-                        renderpass.set_vertex_buffer(v.slot, v.slice());
-                    });
-                    if let Some(indeice) = &draw.indices {
-                        renderpass.set_index_buffer(indeice.slice(), indeice.format);
-                        renderpass.draw_indexed(indeice.value_range(), 0, draw.instances.clone());
-                    } else {
-                        renderpass.draw(draw.vertex.clone(), draw.instances.clone());
-                    }
+                Self::draw_temp(encoder, *viewport, *viewport, draw, target);
+            },
+            (PostProcessDraw::TempScissor(viewport, scissor, draw, target), Some(encoder), None) => {
+                if scissor.2 > 0 && scissor.3 > 0 {
+                    Self::draw_temp(encoder, *viewport, *scissor, draw, target);
                 }
             },
-            (PostProcessDraw::Final(draw), None, Some(renderpass)) => {
+            (PostProcessDraw::Final(draw, viewport, scissor, stencil_reference, target_size), None, Some(renderpass)) => {
                 if let Some(pipeline) = &draw.pipeline {
-                    // renderpass.set_viewport(x as f32, y as f32, w as f32, h as f32, 0., 1.);
-                    // renderpass.set_scissor_rect(x, y, w, h);
+                    // 空裁剪区域不绘制, 也不改动 RenderPass 状态
+                    if let Some((_, _, w, h)) = *scissor {
+                        if w == 0 || h == 0 { return; }
+                    }
+                    if let Some((x, y, w, h)) = *viewport {
+                        renderpass.set_viewport(x, y, w, h, 0., 1.);
+                    }
+                    if let Some((x, y, w, h)) = *scissor {
+                        renderpass.set_scissor_rect(x, y, w, h);
+                    }
+                    if let Some(reference) = *stencil_reference {
+                        renderpass.set_stencil_reference(reference);
+                    }
                     renderpass.set_pipeline(pipeline);
                     draw.bindgroups.set(renderpass);
                     draw.vertices.iter().for_each(|(v, _)| {
@@ -123,6 +174,17 @@ impl PostProcessDraw {
                     } else {
                         renderpass.draw(draw.vertex.clone(), draw.instances.clone());
                     }
+
+                    let (w, h) = *target_size;
+                    if viewport.is_some() {
+                        renderpass.set_viewport(0., 0., w as f32, h as f32, 0., 1.);
+                    }
+                    if scissor.is_some() {
+                        renderpass.set_scissor_rect(0, 0, w, h);
+                    }
+                    if stencil_reference.is_some() {
+                        renderpass.set_stencil_reference(0);
+                    }
                 }
             },
             _ => {
@@ -130,6 +192,49 @@ impl PostProcessDraw {
             }
         }
     }
+    fn draw_temp<'a>(
+        encoder: &'a mut CommandEncoder,
+        viewport: (u32, u32, u32, u32),
+        scissor: (u32, u32, u32, u32),
+        draw: &'a DrawObj,
+        target: &'a ETextureViewUsage,
+    ) {
+        let (x, y, w, h) = viewport;
+        if let Some(pipeline) = &draw.pipeline {
+            let mut renderpass = encoder.begin_render_pass(
+                &wgpu::RenderPassDescriptor {
+                    label: None,
+                    color_attachments: &[
+                        Some(wgpu::RenderPassColorAttachment {
+                            view: target.view(),
+                            resolve_target: None,
+                            ops: wgpu::Operations {
+                                load: wgpu::LoadOp::Load,
+                                store: wgpu::StoreOp::Store,
+                            }
+                        })
+                    ],
+                    depth_stencil_attachment: None,
+                    timestamp_writes: None,
+                    occlusion_query_set: None,
+                }
+            );
+            // log::warn!("Viewport: {:?}", (x as f32, y as f32, w as f32, h as f32, target.key()));
+            renderpass.set_viewport(x as f32, y as f32, w as f32, h as f32, 0., 1.);
+            renderpass.set_scissor_rect(scissor.0, scissor.1, scissor.2, scissor.3);
+            renderpass.set_pipeline(pipeline);
+            draw.bindgroups.set(&mut renderpass);
+            draw.vertices.iter().for_each(|(v, _)| {
+                renderpass.set_vertex_buffer(v.slot, v.slice());
+            });
+            if let Some(indeice) = &draw.indices {
+                renderpass.set_index_buffer(indeice.slice(), indeice.format);
+                renderpass.draw_indexed(indeice.value_range(), 0, draw.instances.clone());
+            } else {
+                renderpass.draw(draw.vertex.clone(), draw.instances.clone());
+            }
+        }
+    }
 }
 
 pub struct ImageEffectUniformBuffer {
@@ -164,10 +269,81 @@ impl Drop for ImageEffectUniformBuffer {
     }
 }
 
-pub struct ImageEffectInstanceBufferOffset(pub(crate) usize, pub(crate) Share<SegQueue<usize>>);
+/// 实例数据区间 - 释放时归还所在尺寸级别的空闲列表
+pub struct ImageEffectInstanceBufferOffset {
+    pub(crate) buffer: Share<EVertexBufferRange>,
+    /// 区间在 buffer 中的起始字节
+    pub(crate) offset: usize,
+    /// 尺寸级别 - 区间字节数为 INSTANCE_RANGE_SIZE << level
+    level: usize,
+    pool: Share<ImageEffectInstancePool>,
+}
+impl ImageEffectInstanceBufferOffset {
+    /// 区间字节数
+    pub fn size(&self) -> usize {
+        ImageEffectInstancePool::range_size(self.level)
+    }
+}
 impl Drop for ImageEffectInstanceBufferOffset {
     fn drop(&mut self) {
-        self.1.push(self.0)
+        self.pool.free[self.level].push((self.buffer.clone(), self.offset))
+    }
+}
+
+/// 实例数据缓冲池 - 空闲区间不足时按需创建新的缓冲
+/// * 区间尺寸按 2 的幂分级, 每级从各自的缓冲中切分
+/// * 缓冲总数达到 max_block_count 时分配失败
+pub struct ImageEffectInstancePool {
+    free: Vec<SegQueue<(Share<EVertexBufferRange>, usize)>>,
+    vballocator: Mutex<VertexBufferAllocator>,
+    block_count: AtomicUsize,
+    max_block_count: AtomicUsize,
+}
+impl ImageEffectInstancePool {
+    /// 单个缓冲字节数, 也是单个区间的最大字节数
+    pub const BLOCK_SIZE: usize = SingleImageEffectResource::INSTANCE_RANGE_SIZE * SingleImageEffectResource::MAX_INSTANCE_RANGE_COUNT;
+    pub const LEVEL_COUNT: usize = (Self::BLOCK_SIZE / SingleImageEffectResource::INSTANCE_RANGE_SIZE).trailing_zeros() as usize + 1;
+    pub const DEFAULT_MAX_BLOCK_COUNT: usize = 64;
+    pub fn new(vballocator: VertexBufferAllocator) -> Self {
+        let mut free = Vec::with_capacity(Self::LEVEL_COUNT);
+        for _ in 0..Self::LEVEL_COUNT {
+            free.push(SegQueue::new());
+        }
+        Self { free, vballocator: Mutex::new(vballocator), block_count: AtomicUsize::new(0), max_block_count: AtomicUsize::new(Self::DEFAULT_MAX_BLOCK_COUNT) }
+    }
+    pub fn range_size(level: usize) -> usize {
+        SingleImageEffectResource::INSTANCE_RANGE_SIZE << level
+    }
+    /// 容纳 size 字节的最小级别
+    pub fn level(size: usize) -> Option<usize> {
+        (0..Self::LEVEL_COUNT).find(|level| Self::range_size(*level) >= size)
+    }
+    /// 已创建的缓冲数
+    pub fn block_count(&self) -> usize {
+        self.block_count.load(Ordering::Relaxed)
+    }
+    /// 设置缓冲总数上限 - 不影响已创建的缓冲
+    pub fn set_max_block_count(&self, count: usize) {
+        self.max_block_count.store(count, Ordering::Relaxed);
+    }
+    fn grow(&self, device: &RenderDevice, queue: &RenderQueue, level: usize) -> Result<(), EPostprocessError> {
+        if self.block_count.fetch_add(1, Ordering::Relaxed) >= self.max_block_count.load(Ordering::Relaxed) {
+            self.block_count.fetch_sub(1, Ordering::Relaxed);
+            return Err(EPostprocessError::InstanceBufferExhausted);
+        }
+        let data = vec![0u8; Self::BLOCK_SIZE];
+        let buffer = self.vballocator.lock().unwrap().create_not_updatable_buffer(device, queue, &data, None);
+        if let Some(buffer) = buffer {
+            let buffer = Share::new(buffer);
+            let size = Self::range_size(level);
+            for idx in 0..Self::BLOCK_SIZE / size {
+                self.free[level].push((buffer.clone(), idx * size));
+            }
+            Ok(())
+        } else {
+            self.block_count.fetch_sub(1, Ordering::Relaxed);
+            Err(EPostprocessError::InstanceBufferExhausted)
+        }
     }
 }
 
@@ -179,12 +355,16 @@ pub struct SingleImageEffectResource {
     map: XHashMap<String, Arc<ImageEffectResource>>,
     pub uniforms: Share<SegQueue<Buffer>>,
     pub(crate) device: RenderDevice,
-    pub(crate) instancebuffer: Share<EVertexBufferRange>,
-    pub(crate) instancebufferranges: Share<SegQueue<usize>>,
+    pub(crate) queue: RenderQueue,
+    pub(crate) instancepool: Share<ImageEffectInstancePool>,
+    /// setup 时加载的着色器语言, 默认 GLSL
+    pub shader_language: EShaderLanguage,
 }
 
 impl SingleImageEffectResource {
+    /// 单个实例缓冲包含的最小区间数
     pub const MAX_INSTANCE_RANGE_COUNT: usize = 16;
+    /// 最小区间字节数
     pub const INSTANCE_RANGE_SIZE: usize = 2048;
     pub fn new(device: &RenderDevice, queue: &RenderQueue, vballocator: &mut VertexBufferAllocator) -> Self {
         // let vertices: [f32; 6] = [-0.5, -0.5, 1.5, -0.5, -0.5, 1.5];
@@ -221,17 +401,9 @@ impl SingleImageEffectResource {
         //     format: wgpu::IndexFormat::Uint16,
         // };
 
-        let bytes = Self::INSTANCE_RANGE_SIZE * Self::MAX_INSTANCE_RANGE_COUNT;
-        let mut data = Vec::with_capacity(bytes);
-        for _ in 0..bytes {
-            data.push(0);
-        }
-        let instancebuffer = vballocator.create_not_updatable_buffer(device, queue, &data, None).unwrap();
-
-        let instancebufferranges = Share::new(SegQueue::default());
-        for idx in 0..Self::MAX_INSTANCE_RANGE_COUNT {
-            instancebufferranges.push(idx * Self::INSTANCE_RANGE_SIZE);
-        }
+        let instancepool = Share::new(ImageEffectInstancePool::new(VertexBufferAllocator::new(ImageEffectInstancePool::BLOCK_SIZE as u32, 1000)));
+        // 预先创建一个最小级别的缓冲
+        let _ = instancepool.grow(device, queue, 0);
 
         Self {
             // triangle,
@@ -241,22 +413,27 @@ impl SingleImageEffectResource {
             map: XHashMap::default(),
             uniforms: Share::new(SegQueue::new()),
             device: device.clone(),
-            instancebuffer: Share::new(instancebuffer),
-            instancebufferranges
+            instancepool,
+            queue: queue.clone(),
+            shader_language: EShaderLanguage::Glsl,
         }
     }
     pub fn uniform_buffer(&self) -> Arc<ImageEffectUniformBuffer> {
         Arc::new(ImageEffectUniformBuffer::new(&self.device, self.uniforms.clone()))
     }
-    pub fn instance_range(&self) -> Option<ImageEffectInstanceBufferOffset> {
-        if let Some(offset) = self.instancebufferranges.pop() {
-            Some(
-                ImageEffectInstanceBufferOffset(offset, self.instancebufferranges.clone())
-            )
-        } else {
-            None
+    /// 分配至少 size 字节的实例数据区间 - 空闲区间不足时创建新的缓冲
+    pub fn instance_range(&self, size: usize) -> Result<ImageEffectInstanceBufferOffset, EPostprocessError> {
+        let level = ImageEffectInstancePool::level(size).ok_or(EPostprocessError::InstanceBufferExhausted)?;
+        loop {
+            if let Some((buffer, offset)) = self.instancepool.free[level].pop() {
+                return Ok(ImageEffectInstanceBufferOffset { buffer, offset, level, pool: self.instancepool.clone() });
+            }
+            self.instancepool.grow(&self.device, &self.queue, level)?;
         }
     }
+    pub fn instance_pool(&self) -> &ImageEffectInstancePool {
+        &self.instancepool
+    }
     pub fn regist(
         &mut self,
         key: String,
@@ -274,22 +451,28 @@ impl SingleImageEffectResource {
 }
 
 pub trait TImageEffect {
-    fn get_target(target: Option<PostprocessTexture>, source: &PostprocessTexture, dst_size: (u32, u32), safeatlas: &SafeAtlasAllocator, target_type: TargetType, target_format: wgpu::TextureFormat, onlyonce: bool) -> PostprocessTexture {
-        let mut templist = vec![];
-        let target = if let Some(target) = target {
-            target
-        } else if let Some(temp) = source.get_share_target() {
-            templist.push(temp);
-            let target = safeatlas.allocate_not_share(dst_size.0, dst_size.1, target_type, templist.iter(), true);
-            PostprocessTexture::from_share_target(Share::new(target), target_format)
-        } else {
-            let target = safeatlas.allocate_not_share(dst_size.0, dst_size.1, target_type, templist.iter(), true);
-            PostprocessTexture::from_share_target(Share::new(target), target_format)
-        };
-        target
+    /// 获取渲染目标
+    /// * `target`
+    ///   * 指定目标 - 原地渲染, 或 TempTargetPool 中回收的中间结果; None 时从图集新分配
+    ///   * 中间结果的回收由调用方的 TempTargetPool 负责
+    fn get_target(target: Option<PostprocessTexture>, source: &PostprocessTexture, dst_size: (u32, u32), safeatlas: &SafeAtlasAllocator, target_type: TargetType, target_format: wgpu::TextureFormat) -> PostprocessTexture {
+        Self::get_target_excluding(target, &[source], dst_size, safeatlas, target_type, target_format)
+    }
+    /// 获取渲染目标 - 新分配的目标避开 sources 所在的纹理
+    /// * `sources`
+    ///   * 本次绘制采样的所有纹理, 目标与其中任一同纹理时, 同一 Pass 既采样又写入
+    fn get_target_excluding(target: Option<PostprocessTexture>, sources: &[&PostprocessTexture], dst_size: (u32, u32), safeatlas: &SafeAtlasAllocator, target_type: TargetType, target_format: wgpu::TextureFormat) -> PostprocessTexture {
+        if let Some(target) = target {
+            return target;
+        }
+        let templist: Vec<_> = sources.iter().filter_map(|source| source.get_share_target()).collect();
+        let target = safeatlas.allocate_not_share(dst_size.0, dst_size.1, target_type, templist.iter(), true);
+        PostprocessTexture::from_share_target(Share::new(target), target_format)
     }
     const SAMPLER_DESC: SamplerDesc;
     const KEY: &'static str;
+    /// 着色器文件名 (顶点, 片元) - 不含扩展名, 热重载时据此从目录读取
+    const SHADER_FILES: (&'static str, &'static str);
     fn bind_group<P: TEffectForBuffer>(
         device: &RenderDevice,
         queue: &RenderQueue,
@@ -320,45 +503,191 @@ pub trait TImageEffect {
         );
         bind_group
     }
-    fn shader(device: &RenderDevice) -> Shader;
+    /// 是否读取深度纹理 - 为 true 时绑定组追加 3: 深度纹理, 4: 深度采样器
+    const DEPTH_INPUT: bool = false;
+    /// 带深度纹理的绑定组 - 深度纹理始终使用最近点采样
+    fn bind_group_with_depth<P: TEffectForBuffer>(
+        device: &RenderDevice,
+        queue: &RenderQueue,
+        param: &P,
+        resource: &ImageEffectResource,
+        delta_time: u64,
+        dst_size: (u32, u32),
+        geo_matrix: &[f32],
+        tex_matrix: (f32, f32, f32, f32),
+        alpha: f32, depth: f32,
+        source: &PostprocessTexture,
+        depth_texture: &PostprocessTexture,
+        force_nearest_filter: bool,
+        src_premultiplied: bool,
+        dst_premultiply: bool,
+    ) -> BindGroup {
+        let param_buffer = param.buffer(delta_time, geo_matrix, tex_matrix, alpha, depth, device, queue, (source.use_w(), source.use_h()), dst_size, src_premultiplied, dst_premultiply);
+        let sampler = if force_nearest_filter { &resource.sampler_nearest.0 } else { &resource.sampler.0 };
+        let bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some(Self::KEY),
+                layout: &resource.bindgroup_layout,
+                entries: &[
+                    wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::Buffer(wgpu::BufferBinding { buffer: &param_buffer, offset: 0, size: None  } )  },
+                    wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::TextureView(source.view())  },
+                    wgpu::BindGroupEntry { binding: 2, resource: wgpu::BindingResource::Sampler(sampler)  },
+                    wgpu::BindGroupEntry { binding: 3, resource: wgpu::BindingResource::TextureView(depth_texture.view())  },
+                    wgpu::BindGroupEntry { binding: 4, resource: wgpu::BindingResource::Sampler(&resource.sampler_nearest.0)  },
+                ],
+            }
+        );
+        bind_group
+    }
+    /// 是否读取第二张纹理 - 为 true 时绑定组追加 3: 纹理, 4: 线性采样器
+    const SECOND_INPUT: bool = false;
+    /// 带第二张纹理的绑定组 - 第二张纹理的使用区域由调用方写入 Uniform
+    fn bind_group_with_second(
+        device: &RenderDevice,
+        resource: &ImageEffectResource,
+        param_buffer: &Buffer,
+        source: &PostprocessTexture,
+        second: &PostprocessTexture,
+        force_nearest_filter: bool,
+    ) -> BindGroup {
+        let sampler = if force_nearest_filter { &resource.sampler_nearest.0 } else { &resource.sampler.0 };
+        device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some(Self::KEY),
+                layout: &resource.bindgroup_layout,
+                entries: &[
+                    wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::Buffer(wgpu::BufferBinding { buffer: param_buffer, offset: 0, size: None  } )  },
+                    wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::TextureView(source.view())  },
+                    wgpu::BindGroupEntry { binding: 2, resource: wgpu::BindingResource::Sampler(sampler)  },
+                    wgpu::BindGroupEntry { binding: 3, resource: wgpu::BindingResource::TextureView(second.view())  },
+                    wgpu::BindGroupEntry { binding: 4, resource: wgpu::BindingResource::Sampler(&resource.sampler.0)  },
+                ],
+            }
+        )
+    }
+    /// 着色器可选宏 - 第 i 位对应 DEFINES[i]
+    const DEFINES: &'static [&'static str] = &[];
+    fn shader(device: &RenderDevice, language: EShaderLanguage) -> Shader;
+    /// 以指定宏编译着色器 - 声明了 DEFINES 的效果需要实现
+    fn shader_with_defines(device: &RenderDevice, language: EShaderLanguage, _defines: &[(&str, &str)]) -> Shader {
+        Self::shader(device, language)
+    }
+    /// 获取启用宏集合对应的着色器, 首次使用时编译并缓存
+    fn shader_variant(device: &RenderDevice, resource: &ImageEffectResource, defines: u32) -> Arc<Shader> {
+        if defines == 0 {
+            return resource.shader.clone();
+        }
+        let mut variants = resource.variants.lock().unwrap();
+        if let Some(shader) = variants.get(&defines) {
+            shader.clone()
+        } else {
+            let list: Vec<(&str, &str)> = Self::DEFINES.iter().enumerate()
+                .filter(|(idx, _)| defines & (1 << idx) != 0)
+                .map(|(_, name)| (*name, "1"))
+                .collect();
+            let shader = if let Some(source) = &resource.source {
+                Arc::new(load_shader_source(device, resource.language, &source.0, &source.1, Self::KEY, Self::DEFINES, &list))
+            } else {
+                Arc::new(Self::shader_with_defines(device, resource.language, &list))
+            };
+            variants.insert(defines, shader.clone());
+            shader
+        }
+    }
     fn pipeline(
         device: &RenderDevice,
         shader: &Shader,
         pipeline_layout: &wgpu::PipelineLayout,
         key_pipeline: &KeyPostprocessPipeline,
     ) -> RenderPipeline;
+    /// 缓存新建的管线 - 多线程并行准备时其他线程可能已插入相同 Key, 此时使用已缓存的管线
+    /// * Key 记录在 resource 中, 热重载时据此移除旧管线
+    /// * 插入失败后已缓存的管线又被热重载移除时, 以新建的管线重新插入
+    fn cache_pipeline(
+        pipelines: &Share<AssetMgr<RenderRes<RenderPipeline>>>,
+        resource: &ImageEffectResource,
+        key: u64,
+        pipeline: RenderPipeline,
+    ) -> Handle<RenderRes<RenderPipeline>> {
+        let mut pipeline = RenderRes::new(pipeline, ASSET_SIZE_FOR_UNKOWN);
+        loop {
+            match pipelines.insert(key, pipeline) {
+                Ok(pipeline) => {
+                    resource.pipeline_keys.lock().unwrap().push(key);
+                    return pipeline;
+                },
+                Err(rejected) => match pipelines.get(&key) {
+                    Some(cached) => return cached,
+                    None => pipeline = rejected,
+                },
+            }
+        }
+    }
     fn setup(
         device: &RenderDevice,
         resources: &mut SingleImageEffectResource,
         samplers: & Share<AssetMgr<SamplerRes>>,
     ) {
-        let shader = Self::shader(device);
+        let shader = Self::shader(device, resources.shader_language);
+        let mut entries = vec![
+            // Param
+            wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                ty: wgpu::BindingType::Buffer { ty: wgpu::BufferBindingType::Uniform, has_dynamic_offset: false, min_binding_size: None },
+                count: None,
+            },
+            // Texture
+            wgpu::BindGroupLayoutEntry {
+                binding: 1,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Texture { sample_type: wgpu::TextureSampleType::Float { filterable: true }, view_dimension: wgpu::TextureViewDimension::D2, multisampled: false },
+                count: None,
+            },
+            // Sampler
+            wgpu::BindGroupLayoutEntry {
+                binding: 2,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                count: None,
+            },
+        ];
+        if Self::DEPTH_INPUT {
+            // Depth Texture - 深度格式只能非过滤采样
+            entries.push(wgpu::BindGroupLayoutEntry {
+                binding: 3,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Texture { sample_type: wgpu::TextureSampleType::Float { filterable: false }, view_dimension: wgpu::TextureViewDimension::D2, multisampled: false },
+                count: None,
+            });
+            // Depth Sampler
+            entries.push(wgpu::BindGroupLayoutEntry {
+                binding: 4,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::NonFiltering),
+                count: None,
+            });
+        }
+        if Self::SECOND_INPUT {
+            // Second Texture
+            entries.push(wgpu::BindGroupLayoutEntry {
+                binding: 3,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Texture { sample_type: wgpu::TextureSampleType::Float { filterable: true }, view_dimension: wgpu::TextureViewDimension::D2, multisampled: false },
+                count: None,
+            });
+            // Second Sampler
+            entries.push(wgpu::BindGroupLayoutEntry {
+                binding: 4,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                count: None,
+            });
+        }
         let bindgroup_layout = device.create_bind_group_layout(
             &wgpu::BindGroupLayoutDescriptor {
                 label: Some(Self::KEY),
-                entries: &[
-                    // Param
-                    wgpu::BindGroupLayoutEntry {
-                        binding: 0,
-                        visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
-                        ty: wgpu::BindingType::Buffer { ty: wgpu::BufferBindingType::Uniform, has_dynamic_offset: false, min_binding_size: None },
-                        count: None,
-                    },
-                    // Texture
-                    wgpu::BindGroupLayoutEntry {
-                        binding: 1,
-                        visibility: wgpu::ShaderStages::FRAGMENT,
-                        ty: wgpu::BindingType::Texture { sample_type: wgpu::TextureSampleType::Float { filterable: true }, view_dimension: wgpu::TextureViewDimension::D2, multisampled: false },
-                        count: None,
-                    },
-                    // Sampler
-                    wgpu::BindGroupLayoutEntry {
-                        binding: 2,
-                        visibility: wgpu::ShaderStages::FRAGMENT,
-                        ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
-                        count: None,
-                    },
-                ],
+                entries: &entries,
             }
         );
 
@@ -374,11 +703,6 @@ pub trait TImageEffect {
             samplers.insert(ImageEffectResource::NEAREST_FILTER.clone(), SamplerRes::new(device, &&ImageEffectResource::NEAREST_FILTER)).ok().unwrap()
         };
 
-        resources.regist(String::from(Self::KEY), ImageEffectResource {
-            shader,
-            sampler: sampler_linear,
-            sampler_nearest,
-            bindgroup_layout,
-        });
+        resources.regist(String::from(Self::KEY), ImageEffectResource::new(shader, sampler_linear, sampler_nearest, bindgroup_layout, resources.shader_language));
     }
 }
@@ -12,12 +12,12 @@ use pi_render::{
         device::RenderDevice, 
         sampler::{SamplerDesc, EAddressMode, EFilterMode, EAnisotropyClamp}, pipeline::RenderPipeline, asset::RenderRes, RenderQueue, bind_group::BindGroup
     },
-    asset::{TAssetKeyU64, ASSET_SIZE_FOR_UNKOWN},
+    asset::TAssetKeyU64,
     components::view::target_alloc::{SafeAtlasAllocator, TargetType}
 };
 use pi_share::Share;
 
-use crate::{material::tools::load_shader, temprory_render_target::PostprocessTexture, effect::*};
+use crate::{material::tools::{load_shader, load_shader_wgsl, EShaderLanguage}, temprory_render_target::PostprocessTexture, effect::*};
 
 use super::{base::{TImageEffect, KeyPostprocessPipeline}, ImageEffectResource};
 
@@ -54,7 +54,7 @@ impl EffectBlurGauss {
             let mut bindgroups = DrawBindGroups::default();
             bindgroups.insert_group(0, DrawBindGroup::Arc(Arc::new(bind_group)));
 
-            let key_pipeline = KeyPostprocessPipeline { key: String::from(Self::KEY), depth_stencil, color_state };
+            let key_pipeline = KeyPostprocessPipeline { key: String::from(Self::KEY), defines: 0, version: resource.version, depth_stencil, color_state };
             let key_pipeline_u64 = key_pipeline.asset_u64();
             let pipeline = if let Some(pipeline) = pipelines.get(&key_pipeline_u64) {
                 pipeline
@@ -67,7 +67,7 @@ impl EffectBlurGauss {
                     }
                 );
                 let pipeline = Self::pipeline(device, &resource.shader, &pipeline_layout, &key_pipeline);
-                pipelines.insert(key_pipeline_u64, RenderRes::new(pipeline, ASSET_SIZE_FOR_UNKOWN)).unwrap()
+                Self::cache_pipeline(pipelines, &resource, key_pipeline_u64, pipeline)
             };
 
             let mut draw = DrawObj {
@@ -100,15 +100,25 @@ impl TImageEffect for EffectBlurGauss {
         border_color: None,
     };
     const KEY: &'static str = "EffectBlurGauss";
-
-    fn shader(device: &RenderDevice) -> crate::material::tools::Shader {
-        load_shader(
-            device,
-            include_str!("../shaders/blur_gauss.vert"),
-            include_str!("../shaders/blur_gauss.frag"),
-            "blur_gauss",
-            "blur_gauss"
-        )
+    const SHADER_FILES: (&'static str, &'static str) = ("blur_gauss", "blur_gauss");
+
+    fn shader(device: &RenderDevice, language: EShaderLanguage) -> crate::material::tools::Shader {
+        match language {
+            EShaderLanguage::Glsl => load_shader(
+                device,
+                include_str!("../shaders/blur_gauss.vert"),
+                include_str!("../shaders/blur_gauss.frag"),
+                "blur_gauss",
+                "blur_gauss"
+            ),
+            EShaderLanguage::Wgsl => load_shader_wgsl(
+                device,
+                include_str!("../shaders/blur_gauss.vert.wgsl"),
+                include_str!("../shaders/blur_gauss.frag.wgsl"),
+                "blur_gauss",
+                "blur_gauss"
+            ),
+        }
     }
 
     fn bind_group<P: TEffectForBuffer>(
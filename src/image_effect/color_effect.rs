@@ -12,12 +12,12 @@ use pi_render::{
         device::RenderDevice, 
         sampler::{SamplerDesc, EAddressMode, EFilterMode, EAnisotropyClamp}, pipeline::RenderPipeline, asset::RenderRes
     },
-    asset::{TAssetKeyU64, ASSET_SIZE_FOR_UNKOWN},
+    asset::TAssetKeyU64,
     components::view::target_alloc::{SafeAtlasAllocator, TargetType}
 };
 use pi_share::Share;
 
-use crate::{material::tools::load_shader, temprory_render_target::PostprocessTexture, effect::*};
+use crate::{material::tools::{load_shader_with_defines, load_shader_wgsl, wgsl_defines, EShaderLanguage}, temprory_render_target::PostprocessTexture, effect::*};
 
 use super::base::{TImageEffect, KeyPostprocessPipeline};
 
@@ -62,7 +62,8 @@ impl EffectColorEffect {
             let mut bindgroups = DrawBindGroups::default();
             bindgroups.insert_group(0, DrawBindGroup::Arc(Arc::new(bind_group)));
 
-            let key_pipeline = KeyPostprocessPipeline { key: String::from(Self::KEY), depth_stencil, color_state };
+            let defines = param.defines();
+            let key_pipeline = KeyPostprocessPipeline { key: String::from(Self::KEY), defines, version: resource.version, depth_stencil, color_state };
             let key_pipeline_u64 = key_pipeline.asset_u64();
             let pipeline = if let Some(pipeline) = pipelines.get(&key_pipeline_u64) {
                 pipeline
@@ -74,8 +75,9 @@ impl EffectColorEffect {
                         push_constant_ranges: &[],
                     }
                 );
-                let pipeline = Self::pipeline(device, &resource.shader, &pipeline_layout, &key_pipeline);
-                pipelines.insert(key_pipeline_u64, RenderRes::new(pipeline, ASSET_SIZE_FOR_UNKOWN)).unwrap()
+                let shader = Self::shader_variant(device, &resource, defines);
+                let pipeline = Self::pipeline(device, &shader, &pipeline_layout, &key_pipeline);
+                Self::cache_pipeline(pipelines, &resource, key_pipeline_u64, pipeline)
             };
 
             let mut draw = DrawObj {
@@ -108,15 +110,36 @@ impl TImageEffect for EffectColorEffect {
         border_color: None,
     };
     const KEY: &'static str = "EffectColorEffect";
+    const SHADER_FILES: (&'static str, &'static str) = ("color_effect", "color_effect");
+    /// 与 ColorEffectRenderer::DEFINE_* 位序一致
+    const DEFINES: &'static [&'static str] = &["COLOR_BALANCE", "HSB", "COLOR_SCALE", "VIGNETTE", "COLOR_FILTER"];
 
-    fn shader(device: &RenderDevice) -> crate::material::tools::Shader {
-        load_shader(
-            device,
-            include_str!("../shaders/color_effect.vert"),
-            include_str!("../shaders/color_effect.frag"),
-            "color_effect",
-            "color_effect"
-        )
+    fn shader(device: &RenderDevice, language: EShaderLanguage) -> crate::material::tools::Shader {
+        Self::shader_with_defines(device, language, &[])
+    }
+
+    fn shader_with_defines(device: &RenderDevice, language: EShaderLanguage, defines: &[(&str, &str)]) -> crate::material::tools::Shader {
+        match language {
+            EShaderLanguage::Glsl => load_shader_with_defines(
+                device,
+                include_str!("../shaders/color_effect.vert"),
+                include_str!("../shaders/color_effect.frag"),
+                "color_effect",
+                "color_effect",
+                defines
+            ),
+            EShaderLanguage::Wgsl => {
+                // 宏常量只在片元着色器中使用
+                let fs = wgsl_defines(Self::DEFINES, defines) + include_str!("../shaders/color_effect.frag.wgsl");
+                load_shader_wgsl(
+                    device,
+                    include_str!("../shaders/color_effect.vert.wgsl"),
+                    &fs,
+                    "color_effect",
+                    "color_effect"
+                )
+            },
+        }
     }
 
     fn pipeline(
@@ -12,12 +12,12 @@ use pi_render::{
         device::RenderDevice, 
         sampler::{SamplerDesc, EAddressMode, EFilterMode, EAnisotropyClamp}, pipeline::RenderPipeline, asset::RenderRes
     },
-    asset::{TAssetKeyU64, ASSET_SIZE_FOR_UNKOWN},
+    asset::TAssetKeyU64,
     components::view::target_alloc::{SafeAtlasAllocator, TargetType}
 };
 use pi_share::Share;
 
-use crate::{material::tools::load_shader, temprory_render_target::PostprocessTexture, effect::*};
+use crate::{material::tools::{load_shader, load_shader_wgsl, EShaderLanguage}, temprory_render_target::PostprocessTexture, effect::*};
 
 use super::base::{TImageEffect, KeyPostprocessPipeline};
 
@@ -62,7 +62,7 @@ impl EffectCopy {
             let mut bindgroups = DrawBindGroups::default();
             bindgroups.insert_group(0, DrawBindGroup::Arc(Arc::new(bind_group)));
 
-            let key_pipeline = KeyPostprocessPipeline { key: String::from(Self::KEY), depth_stencil, color_state };
+            let key_pipeline = KeyPostprocessPipeline { key: String::from(Self::KEY), defines: 0, version: resource.version, depth_stencil, color_state };
             let key_pipeline_u64 = key_pipeline.asset_u64();
             let pipeline = if let Some(pipeline) = pipelines.get(&key_pipeline_u64) {
                 pipeline
@@ -75,7 +75,7 @@ impl EffectCopy {
                     }
                 );
                 let pipeline = Self::pipeline(device, &resource.shader, &pipeline_layout, &key_pipeline);
-                pipelines.insert(key_pipeline_u64, RenderRes::new(pipeline, ASSET_SIZE_FOR_UNKOWN)).unwrap()
+                Self::cache_pipeline(pipelines, &resource, key_pipeline_u64, pipeline)
             };
 
             let mut draw = DrawObj {
@@ -108,15 +108,25 @@ impl TImageEffect for EffectCopy {
         border_color: None,
     };
     const KEY: &'static str = "EffectCopy";
-
-    fn shader(device: &RenderDevice) -> crate::material::tools::Shader {
-        load_shader(
-            device,
-            include_str!("../shaders/copy.vert"),
-            include_str!("../shaders/copy.frag"),
-            "copy",
-            "copy"
-        )
+    const SHADER_FILES: (&'static str, &'static str) = ("copy", "copy");
+
+    fn shader(device: &RenderDevice, language: EShaderLanguage) -> crate::material::tools::Shader {
+        match language {
+            EShaderLanguage::Glsl => load_shader(
+                device,
+                include_str!("../shaders/copy.vert"),
+                include_str!("../shaders/copy.frag"),
+                "copy",
+                "copy"
+            ),
+            EShaderLanguage::Wgsl => load_shader_wgsl(
+                device,
+                include_str!("../shaders/copy.vert.wgsl"),
+                include_str!("../shaders/copy.frag.wgsl"),
+                "copy",
+                "copy"
+            ),
+        }
     }
 
     fn pipeline(
@@ -0,0 +1,172 @@
+use std::{sync::Arc, ops::Range};
+
+use pi_assets::mgr::AssetMgr;
+use pi_map::smallvecmap::SmallVecMap;
+
+use pi_render::{
+    renderer::{
+        draw_obj::{DrawObj, DrawBindGroups, DrawBindGroup},
+        pipeline::DepthStencilState
+    },
+    rhi::{
+        device::RenderDevice, 
+        sampler::{SamplerDesc, EAddressMode, EFilterMode, EAnisotropyClamp}, pipeline::RenderPipeline, asset::RenderRes, RenderQueue
+    },
+    asset::TAssetKeyU64,
+    components::view::target_alloc::{SafeAtlasAllocator, TargetType}
+};
+use pi_share::Share;
+
+use crate::{temprory_render_target::PostprocessTexture, effect::*, material::tools::{load_shader, load_shader_wgsl, EShaderLanguage}};
+
+use super::base::{TImageEffect, KeyPostprocessPipeline};
+
+
+/// 发光合成 - 按模式将模糊 alpha 生成的光晕与源内容合成
+pub struct EffectGlow {}
+impl EffectGlow {
+    /// * `glow`
+    ///   * 模糊后的源内容, 只读取 alpha
+    pub fn ready(
+        param: &GlowRenderer,
+        glow: &PostprocessTexture,
+        resources: & super::base::SingleImageEffectResource,
+        device: &RenderDevice,
+        queue: &RenderQueue,
+        _delta_time: u64,
+        _dst_size: (u32, u32),
+        geo_matrix: &[f32],
+        alpha: f32, depth: f32,
+        source: &PostprocessTexture,
+        _safeatlas: &SafeAtlasAllocator,
+        _target_type: TargetType,
+        pipelines: & Share<AssetMgr<RenderRes<RenderPipeline>>>,
+        color_state: wgpu::ColorTargetState,
+        depth_stencil: Option<DepthStencilState>,
+        src_premultiplied: bool,
+        dst_premultiply: bool,
+    ) -> Option<DrawObj> {
+        if let Some(resource) = resources.get(&String::from(Self::KEY)) {
+            let param_buffer = param.buffer(geo_matrix, source.get_tilloff(), glow.get_tilloff(), alpha, depth, queue, src_premultiplied, dst_premultiply);
+            let bind_group = Self::bind_group_with_second(device, &resource, param_buffer, source, glow, false);
+
+            // let target = Self::get_target(target, &source, dst_size, safeatlas, target_type);
+
+            // log::info!(">>>>>>>>>> {:?}: {:?} >> {:?}", Self::KEY, source.get_rect(), target.get_rect());
+
+            let mut bindgroups = DrawBindGroups::default();
+            bindgroups.insert_group(0, DrawBindGroup::Arc(Arc::new(bind_group)));
+
+            let key_pipeline = KeyPostprocessPipeline { key: String::from(Self::KEY), defines: 0, version: resource.version, depth_stencil, color_state };
+            let key_pipeline_u64 = key_pipeline.asset_u64();
+            let pipeline = if let Some(pipeline) = pipelines.get(&key_pipeline_u64) {
+                pipeline
+            } else {
+                let pipeline_layout = device.create_pipeline_layout(
+                    &wgpu::PipelineLayoutDescriptor {
+                        label: Some(Self::KEY),
+                        bind_group_layouts: &[&resource.bindgroup_layout.value()],
+                        push_constant_ranges: &[],
+                    }
+                );
+                let pipeline = Self::pipeline(device, &resource.shader, &pipeline_layout, &key_pipeline);
+                Self::cache_pipeline(pipelines, &resource, key_pipeline_u64, pipeline)
+            };
+
+            let mut draw = DrawObj {
+                pipeline: Some(pipeline),
+                bindgroups,
+                vertices: SmallVecMap::default(),
+                instances: Range { start: 0, end: 1 },
+                vertex: resources.quad.value_range(),
+                indices: None,
+            };
+            draw.vertices.insert(0, resources.quad.clone());
+            Some(draw)
+        } else {
+            None
+        }
+
+    }
+}
+impl TImageEffect for EffectGlow {
+
+    const SAMPLER_DESC: SamplerDesc = SamplerDesc {
+        address_mode_u: EAddressMode::ClampToEdge,
+        address_mode_v: EAddressMode::ClampToEdge,
+        address_mode_w: EAddressMode::ClampToEdge,
+        mag_filter: EFilterMode::Linear,
+        min_filter: EFilterMode::Linear,
+        mipmap_filter: EFilterMode::Nearest,
+        compare: None,
+        anisotropy_clamp: EAnisotropyClamp::None,
+        border_color: None,
+    };
+    const KEY: &'static str = "EffectGlow";
+    const SHADER_FILES: (&'static str, &'static str) = ("glow", "glow");
+    const SECOND_INPUT: bool = true;
+
+    fn shader(device: &RenderDevice, language: EShaderLanguage) -> crate::material::tools::Shader {
+        match language {
+            EShaderLanguage::Glsl => load_shader(
+                device,
+                include_str!("../shaders/glow.vert"),
+                include_str!("../shaders/glow.frag"),
+                "glow",
+                "glow"
+            ),
+            EShaderLanguage::Wgsl => load_shader_wgsl(
+                device,
+                include_str!("../shaders/glow.vert.wgsl"),
+                include_str!("../shaders/glow.frag.wgsl"),
+                "glow",
+                "glow"
+            ),
+        }
+    }
+
+    fn pipeline(
+        device: &RenderDevice,
+        shader: &crate::material::tools::Shader,
+        pipeline_layout: &wgpu::PipelineLayout,
+        key_pipeline: &KeyPostprocessPipeline,
+    ) -> RenderPipeline {
+        let base_attributes = vec![
+            wgpu::VertexAttribute { format: wgpu::VertexFormat::Float32x2, offset: 0, shader_location: 0 },
+        ];
+
+        device.create_render_pipeline(
+            &wgpu::RenderPipelineDescriptor {
+                label: Some(Self::KEY),
+                layout: Some(&pipeline_layout),
+                vertex: wgpu::VertexState {
+                    module: &shader.vs_module,
+                    entry_point: Some("main"),
+                    buffers: &[
+                        wgpu::VertexBufferLayout { array_stride: 8, step_mode: wgpu::VertexStepMode::Vertex, attributes: &base_attributes  }
+                    ],
+                    compilation_options: wgpu::PipelineCompilationOptions::default(),
+                },
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    strip_index_format: None,
+                    unclipped_depth: false,
+                    polygon_mode: wgpu::PolygonMode::Fill,
+                    ..Default::default()
+                },
+                depth_stencil: key_pipeline.depth_stencil(),
+                multisample: wgpu::MultisampleState::default(),
+                fragment: Some(
+                    wgpu::FragmentState {
+                        module: &shader.fs_module,
+                        entry_point: Some("main"),
+                        targets: &[key_pipeline.color_state()],
+                        compilation_options: wgpu::PipelineCompilationOptions::default(),
+                    }
+                ),
+                multiview: None,
+                cache: None,
+            }
+        )
+    }
+}
@@ -0,0 +1,157 @@
+use std::{fs, path::PathBuf, time::SystemTime};
+
+use naga::{
+    front::{glsl, wgsl},
+    valid::{Capabilities, ValidationFlags, Validator},
+    FastHashMap, ShaderStage,
+};
+use pi_assets::mgr::AssetMgr;
+use pi_render::rhi::{asset::RenderRes, device::RenderDevice, pipeline::RenderPipeline};
+use pi_share::Share;
+
+use crate::material::tools::{load_shader_source, wgsl_defines, EShaderLanguage};
+
+use super::*;
+
+/// 着色器热重载 - 开发模式使用
+/// * 从指定目录读取效果的着色器源码, 文件修改后重建对应的 ImageEffectResource
+/// * 不监听文件系统, 每次调用 check 时轮询文件修改时间
+/// * 重建后资源版本递增, 管线 Key 随之变化; 旧版本创建的管线同时从管线资产管理器中移除
+/// * 新源码编译失败时保留上一个可用版本
+pub struct ShaderHotReload {
+    dir: PathBuf,
+    entries: Vec<HotReloadEntry>,
+}
+
+struct HotReloadEntry {
+    key: &'static str,
+    files: (&'static str, &'static str),
+    defines: &'static [&'static str],
+    modified: Option<SystemTime>,
+}
+
+impl ShaderHotReload {
+    /// * `dir`
+    ///   * 着色器目录, 文件布局同 src/shaders
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            entries: vec![],
+        }
+    }
+    /// 监视单个效果的着色器
+    pub fn watch<T: TImageEffect>(&mut self) {
+        if self.entries.iter().any(|entry| entry.key == T::KEY) {
+            return;
+        }
+        self.entries.push(HotReloadEntry { key: T::KEY, files: T::SHADER_FILES, defines: T::DEFINES, modified: None });
+    }
+    /// 监视全部内置效果
+    pub fn watch_all(&mut self) {
+        self.watch::<EffectBlurBokeh>();
+        self.watch::<EffectBlurDirect>();
+        self.watch::<EffectBlurDual>();
+        self.watch::<EffectBlurGauss>();
+        self.watch::<EffectBlurRadial>();
+        self.watch::<EffectColorEffect>();
+        self.watch::<EffectCopy>();
+        self.watch::<EffectFilterBrightness>();
+        self.watch::<EffectFilterSobel>();
+        self.watch::<EffectHorizonGlitch>();
+        self.watch::<EffectRadialWave>();
+        self.watch::<EffectImageMask>();
+        self.watch::<EffectClipSdf>();
+        self.watch::<EffectDepthFog>();
+        self.watch::<EffectBlurBokehDepth>();
+        self.watch::<EffectDropShadow>();
+        self.watch::<EffectGlow>();
+        self.watch::<EffectOutline>();
+        self.watch::<EffectJumpFlood>();
+        self.watch::<EffectChromaticAberration>();
+        self.watch::<EffectFilmGrain>();
+        self.watch::<EffectPixelate>();
+    }
+    /// 检查文件修改并重载 - 每帧或定时调用
+    /// * 每次调用读取所有监视文件的修改时间, 调用频率即检查频率
+    /// * 首次检查即从目录加载, 之后仅在文件修改时间变化时重载
+    /// * 返回成功重载的效果 KEY
+    pub fn check(
+        &mut self,
+        device: &RenderDevice,
+        resources: &mut SingleImageEffectResource,
+        pipelines: &Share<AssetMgr<RenderRes<RenderPipeline>>>,
+    ) -> Vec<&'static str> {
+        let language = resources.shader_language;
+        let mut result = vec![];
+        for entry in self.entries.iter_mut() {
+            let (vs_path, fs_path) = match language {
+                EShaderLanguage::Glsl => (
+                    self.dir.join(format!("{}.vert", entry.files.0)),
+                    self.dir.join(format!("{}.frag", entry.files.1)),
+                ),
+                EShaderLanguage::Wgsl => (
+                    self.dir.join(format!("{}.vert.wgsl", entry.files.0)),
+                    self.dir.join(format!("{}.frag.wgsl", entry.files.1)),
+                ),
+            };
+            let modified = match (modified(&vs_path), modified(&fs_path)) {
+                (Some(a), Some(b)) => a.max(b),
+                _ => continue,
+            };
+            if entry.modified == Some(modified) {
+                continue;
+            }
+            // 无论成功与否都记录, 失败的版本不重复编译
+            entry.modified = Some(modified);
+
+            let resource = if let Some(resource) = resources.get(&String::from(entry.key)) { resource } else { continue; };
+            let (vs_text, fs_text) = match (fs::read_to_string(&vs_path), fs::read_to_string(&fs_path)) {
+                (Ok(vs), Ok(fs)) => (vs, fs),
+                _ => continue,
+            };
+            if let Err(err) = validate(language, &vs_text, &fs_text, entry.defines) {
+                log::warn!("ShaderHotReload {}: {}", entry.key, err);
+                continue;
+            }
+
+            let shader = load_shader_source(device, language, &vs_text, &fs_text, entry.key, entry.defines, &[]);
+            // 旧版本的管线不会再命中, 直接移除; 仍被绘制对象持有的管线在释放后回收
+            resource.pipeline_keys.lock().unwrap().drain(..).for_each(|key| { pipelines.remove(&key); });
+            resources.regist(String::from(entry.key), resource.reload(shader, vs_text, fs_text));
+            log::info!("ShaderHotReload {}: reloaded", entry.key);
+            result.push(entry.key);
+        }
+        result
+    }
+}
+
+fn modified(path: &PathBuf) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|meta| meta.modified()).ok()
+}
+
+/// 以 naga 预先校验, 避免设备在创建着色器模块时报错; 带宏的着色器校验 未启用/全部启用 两种组合
+fn validate(language: EShaderLanguage, vs_text: &str, fs_text: &str, all: &[&str]) -> Result<(), String> {
+    let combinations: Vec<&[&str]> = if all.is_empty() { vec![&[]] } else { vec![&[], all] };
+    for enabled in combinations {
+        for (stage, text) in [(ShaderStage::Vertex, vs_text), (ShaderStage::Fragment, fs_text)] {
+            let module = match language {
+                EShaderLanguage::Glsl => {
+                    let mut defines = FastHashMap::default();
+                    enabled.iter().for_each(|key| { defines.insert(key.to_string(), String::from("1")); });
+                    glsl::Frontend::default()
+                        .parse(&glsl::Options { stage, defines }, text)
+                        .map_err(|err| err.emit_to_string(text))?
+                },
+                EShaderLanguage::Wgsl => {
+                    let defines: Vec<(&str, &str)> = enabled.iter().map(|key| (*key, "1")).collect();
+                    let text = wgsl_defines(all, &defines) + text;
+                    wgsl::parse_str(&text).map_err(|err| err.emit_to_string(&text))?
+                },
+            };
+            Validator::new(ValidationFlags::all(), Capabilities::all())
+                .validate(&module)
+                .map_err(|err| format!("{:?}", err.into_inner()))?;
+        }
+    }
+    Ok(())
+}
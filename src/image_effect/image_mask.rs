@@ -12,12 +12,12 @@ use pi_render::{
         device::RenderDevice, 
         sampler::{SamplerDesc, EAddressMode, EFilterMode, EAnisotropyClamp}, pipeline::RenderPipeline, asset::RenderRes
     },
-    asset::{TAssetKeyU64, ASSET_SIZE_FOR_UNKOWN},
+    asset::TAssetKeyU64,
     components::view::target_alloc::{SafeAtlasAllocator, TargetType}
 };
 use pi_share::Share;
 
-use crate::{temprory_render_target::PostprocessTexture, effect::*, material::tools::load_shader};
+use crate::{temprory_render_target::PostprocessTexture, effect::*, material::tools::{load_shader, load_shader_wgsl, EShaderLanguage}};
 
 use super::{base::{TImageEffect, KeyPostprocessPipeline}, SingleImageEffectResource, ImageEffectResource};
 
@@ -68,7 +68,7 @@ impl EffectImageMask {
             let mut bindgroups = DrawBindGroups::default();
             bindgroups.insert_group(0, DrawBindGroup::Arc(Arc::new(bind_group)));
 
-            let key_pipeline = KeyPostprocessPipeline { key: String::from(Self::KEY), depth_stencil, color_state };
+            let key_pipeline = KeyPostprocessPipeline { key: String::from(Self::KEY), defines: 0, version: resource.version, depth_stencil, color_state };
             let key_pipeline_u64 = key_pipeline.asset_u64();
             let pipeline = if let Some(pipeline) = pipelines.get(&key_pipeline_u64) {
                 pipeline
@@ -81,7 +81,7 @@ impl EffectImageMask {
                     }
                 );
                 let pipeline = Self::pipeline(device, &resource.shader, &pipeline_layout, &key_pipeline);
-                pipelines.insert(key_pipeline_u64, RenderRes::new(pipeline, ASSET_SIZE_FOR_UNKOWN)).unwrap()
+                Self::cache_pipeline(pipelines, &resource, key_pipeline_u64, pipeline)
             };
 
             let mut draw = DrawObj {
@@ -114,15 +114,25 @@ impl TImageEffect for EffectImageMask {
         border_color: None,
     };
     const KEY: &'static str = "EffectImageMask";
-
-    fn shader(device: &RenderDevice) -> crate::material::tools::Shader {
-        load_shader(
-            device,
-            include_str!("../shaders/image_mask.vert"),
-            include_str!("../shaders/image_mask.frag"),
-            "image_mask",
-            "image_mask"
-        )
+    const SHADER_FILES: (&'static str, &'static str) = ("image_mask", "image_mask");
+
+    fn shader(device: &RenderDevice, language: EShaderLanguage) -> crate::material::tools::Shader {
+        match language {
+            EShaderLanguage::Glsl => load_shader(
+                device,
+                include_str!("../shaders/image_mask.vert"),
+                include_str!("../shaders/image_mask.frag"),
+                "image_mask",
+                "image_mask"
+            ),
+            EShaderLanguage::Wgsl => load_shader_wgsl(
+                device,
+                include_str!("../shaders/image_mask.vert.wgsl"),
+                include_str!("../shaders/image_mask.frag.wgsl"),
+                "image_mask",
+                "image_mask"
+            ),
+        }
     }
 
     fn pipeline(
@@ -174,7 +184,7 @@ impl TImageEffect for EffectImageMask {
         resources: &mut SingleImageEffectResource,
         samplers: & Share<AssetMgr<pi_render::renderer::sampler::SamplerRes>>,
     ) {
-        let shader = Self::shader(device);
+        let shader = Self::shader(device, resources.shader_language);
         let bindgroup_layout = device.create_bind_group_layout(
             &wgpu::BindGroupLayoutDescriptor {
                 label: Some(Self::KEY),
@@ -230,11 +240,6 @@ impl TImageEffect for EffectImageMask {
             samplers.insert(ImageEffectResource::NEAREST_FILTER.clone(), SamplerRes::new(device, &&ImageEffectResource::NEAREST_FILTER)).ok().unwrap()
         };
 
-        resources.regist(String::from(Self::KEY), ImageEffectResource {
-            shader,
-            sampler: sampler_linear,
-            sampler_nearest,
-            bindgroup_layout,
-        });
+        resources.regist(String::from(Self::KEY), ImageEffectResource::new(shader, sampler_linear, sampler_nearest, bindgroup_layout, resources.shader_language));
     }
 }
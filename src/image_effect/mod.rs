@@ -12,6 +12,16 @@ mod horizon_glitch;
 mod radial_wave;
 mod image_mask;
 mod clip_sdf;
+mod depth_fog;
+mod blur_bokeh_depth;
+mod drop_shadow;
+mod glow;
+mod outline;
+mod jump_flood;
+mod chromatic_aberration;
+mod film_grain;
+mod pixelate;
+mod hot_reload;
 
 pub use base::*;
 pub use blur_bokeh::*;
@@ -26,4 +36,14 @@ pub use filter_sobel::*;
 pub use horizon_glitch::*;
 pub use radial_wave::*;
 pub use image_mask::*;
-pub use clip_sdf::*;
\ No newline at end of file
+pub use clip_sdf::*;
+pub use depth_fog::*;
+pub use blur_bokeh_depth::*;
+pub use drop_shadow::*;
+pub use glow::*;
+pub use outline::*;
+pub use jump_flood::*;
+pub use chromatic_aberration::*;
+pub use film_grain::*;
+pub use pixelate::*;
+pub use hot_reload::*;
\ No newline at end of file
@@ -2,6 +2,8 @@
 
 
 mod temprory_render_target;
+mod stencil;
+mod time;
 // pub mod postprocess_geometry;
 pub mod postprocess;
 pub mod effect;
@@ -13,6 +15,7 @@ mod renderer;
 pub mod error;
 pub mod image_effect;
 pub mod prelude;
+pub mod plugin;
 
 mod postprocess_flags;
 
@@ -3,6 +3,8 @@
 // pub mod shader;
 pub mod tools;
 pub mod blend;
+#[cfg(test)]
+mod shader_check;
 
 // pub mod depth_and_stencil;
 // pub mod pipeline;
@@ -0,0 +1,166 @@
+// 着色器校验 - build.rs 以 include! 引入, 测试中直接使用
+// * GLSL 与 WGSL 版本都需通过 naga 解析与验证
+// * 同一效果的 顶点/片元, GLSL/WGSL 声明的 Uniform 块布局必须一致
+// 此文件只能依赖 std 与 naga
+
+use std::{fs, path::Path};
+
+use naga::{
+    front::{glsl, wgsl},
+    valid::{Capabilities, ValidationFlags, Validator},
+    AddressSpace, FastHashMap, Module, ShaderStage, TypeInner,
+};
+
+/// 着色器目录 - 相对 crate 根目录
+const SHADER_DIR: &str = "src/shaders";
+
+/// 带宏的着色器 - 需与对应 TImageEffect::DEFINES 保持一致
+const SHADER_DEFINES: &[(&str, &[&str])] = &[
+    ("color_effect", &["COLOR_BALANCE", "HSB", "COLOR_SCALE", "VIGNETTE", "COLOR_FILTER"]),
+];
+
+/// Uniform 块布局: (成员名称, 偏移, 类型), 总大小
+type UniformLayout = (Vec<(String, u32, String)>, u32);
+
+/// 所有 GLSL 着色器文件名 - 已排序
+fn shader_names() -> Vec<String> {
+    let mut names = vec![];
+    for entry in fs::read_dir(SHADER_DIR).unwrap() {
+        let path = entry.unwrap().path();
+        if let Some(ext) = path.extension().and_then(|v| v.to_str()) {
+            if ext == "vert" || ext == "frag" {
+                names.push(path.file_name().unwrap().to_str().unwrap().to_string());
+            }
+        }
+    }
+    names.sort();
+    names
+}
+
+fn defines_of(name: &str) -> &'static [&'static str] {
+    let stem = name.split('.').next().unwrap();
+    SHADER_DEFINES.iter().find(|(key, _)| *key == stem).map(|(_, defines)| *defines).unwrap_or(&[])
+}
+
+/// GLSL 与对应 WGSL 版本均通过验证, 且 Uniform 块布局一致
+fn check_shader(name: &str) -> Result<(), String> {
+    let defines = defines_of(name);
+    // 不启用任何宏 与 启用全部宏 两种组合
+    let variants: Vec<&[&str]> = if defines.is_empty() { vec![&[]] } else { vec![&[], defines] };
+
+    let wgsl_name = format!("{}.wgsl", name);
+    if !Path::new(SHADER_DIR).join(&wgsl_name).exists() {
+        return Err(format!("{}: missing WGSL version {}", name, wgsl_name));
+    }
+
+    for enabled in variants {
+        let glsl_layout = uniform_of_glsl(name, enabled)?;
+        let wgsl_layout = uniform_of_wgsl(&wgsl_name, defines, enabled)?;
+        if glsl_layout != wgsl_layout {
+            return Err(format!("{} / {}: uniform layout mismatch\n  glsl: {:?}\n  wgsl: {:?}", name, wgsl_name, glsl_layout, wgsl_layout));
+        }
+    }
+    Ok(())
+}
+
+/// 顶点与片元的 Uniform 块一致 - 缺少其一时跳过
+fn check_stage_pair(vert: &str) -> Result<(), String> {
+    let frag = vert.replace(".vert", ".frag");
+    if !Path::new(SHADER_DIR).join(&frag).exists() {
+        return Ok(());
+    }
+    let vs = uniform_of_glsl(vert, &[])?;
+    let fs = uniform_of_glsl(&frag, &[])?;
+    if vs != fs {
+        return Err(format!("{} / {}: uniform layout mismatch\n  vert: {:?}\n  frag: {:?}", vert, frag, vs, fs));
+    }
+    Ok(())
+}
+
+fn read(name: &str) -> Result<String, String> {
+    fs::read_to_string(Path::new(SHADER_DIR).join(name)).map_err(|err| format!("{}: {}", name, err))
+}
+
+fn uniform_of_glsl(name: &str, enabled: &[&str]) -> Result<Option<UniformLayout>, String> {
+    let stage = if name.ends_with(".vert") { ShaderStage::Vertex } else { ShaderStage::Fragment };
+    let mut defines = FastHashMap::default();
+    enabled.iter().for_each(|key| { defines.insert(key.to_string(), String::from("1")); });
+
+    let source = read(name)?;
+    let module = glsl::Frontend::default()
+        .parse(&glsl::Options { stage, defines }, &source)
+        .map_err(|err| format!("{} {:?}: {}", name, enabled, err.emit_to_string(&source)))?;
+    validate(name, &module)?;
+    Ok(uniform_layout(&module))
+}
+
+fn uniform_of_wgsl(name: &str, all: &[&str], enabled: &[&str]) -> Result<Option<UniformLayout>, String> {
+    // 与 material::tools::wgsl_defines 一致
+    let mut source = String::new();
+    all.iter().for_each(|key| {
+        source.push_str(&format!("const {}: bool = {};\n", key, enabled.contains(key)));
+    });
+    source.push_str(&read(name)?);
+
+    let module = wgsl::parse_str(&source)
+        .map_err(|err| format!("{} {:?}: {}", name, enabled, err.emit_to_string(&source)))?;
+    validate(name, &module)?;
+    Ok(uniform_layout(&module))
+}
+
+fn validate(name: &str, module: &Module) -> Result<(), String> {
+    Validator::new(ValidationFlags::all(), Capabilities::all())
+        .validate(module)
+        .map(|_| ())
+        .map_err(|err| format!("{}: {:?}", name, err.into_inner()))
+}
+
+/// group 0 binding 0 的 Uniform 块
+fn uniform_layout(module: &Module) -> Option<UniformLayout> {
+    let (_, var) = module.global_variables.iter().find(|(_, var)| {
+        var.space == AddressSpace::Uniform
+            && var.binding.as_ref().map(|v| v.group == 0 && v.binding == 0).unwrap_or(false)
+    })?;
+    match &module.types[var.ty].inner {
+        TypeInner::Struct { members, span } => {
+            let members = members.iter().map(|member| {
+                (
+                    member.name.clone().unwrap_or_default(),
+                    member.offset,
+                    type_name(&module.types[member.ty].inner),
+                )
+            }).collect();
+            Some((members, *span))
+        },
+        _ => None,
+    }
+}
+
+fn type_name(inner: &TypeInner) -> String {
+    match inner {
+        TypeInner::Scalar(scalar) => format!("{:?}", scalar.kind),
+        TypeInner::Vector { size, scalar } => format!("vec{}<{:?}>", *size as u8, scalar.kind),
+        TypeInner::Matrix { columns, rows, scalar } => format!("mat{}x{}<{:?}>", *columns as u8, *rows as u8, scalar.kind),
+        _ => String::from("other"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glsl_and_wgsl_validate_with_same_uniform() {
+        let errors: Vec<String> = shader_names().iter().filter_map(|name| check_shader(name).err()).collect();
+        assert!(errors.is_empty(), "{}", errors.join("\n"));
+    }
+
+    #[test]
+    fn vert_and_frag_share_uniform() {
+        let errors: Vec<String> = shader_names().iter()
+            .filter(|name| name.ends_with(".vert"))
+            .filter_map(|name| check_stage_pair(name).err())
+            .collect();
+        assert!(errors.is_empty(), "{}", errors.join("\n"));
+    }
+}
@@ -35,6 +35,16 @@
 //     }
 // }
 
+/// 着色器源码语言
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum EShaderLanguage {
+    /// src/shaders/*.vert, *.frag
+    #[default]
+    Glsl,
+    /// src/shaders/*.vert.wgsl, *.frag.wgsl
+    Wgsl,
+}
+
 pub struct Shader {
     pub vs_module: wgpu::ShaderModule,
     pub fs_module: wgpu::ShaderModule,
@@ -83,6 +93,20 @@ pub fn load_shader(
     fs_text: &str,
     vs_label: &str,
     fs_label: &str,
+) -> Shader {
+    load_shader_with_defines(device, vs_text, fs_text, vs_label, fs_label, &[])
+}
+
+/// 加载着色器 - 附带预处理宏定义
+/// * `defines`
+///   * (宏名称, 宏值) 列表, 同时作用于顶点和片元着色器
+pub fn load_shader_with_defines(
+    device: &wgpu::Device,
+    vs_text: &str,
+    fs_text: &str,
+    vs_label: &str,
+    fs_label: &str,
+    defines: &[(&str, &str)],
 ) -> Shader {
     let vs_module = device.create_shader_module(
         wgpu::ShaderModuleDescriptor {
@@ -90,7 +114,7 @@ pub fn load_shader(
             source: wgpu::ShaderSource::Glsl {
                 shader: std::borrow::Cow::Borrowed(vs_text),
                 stage: naga::ShaderStage::Vertex,
-                defines: &[],
+                defines,
             }
         }
     );
@@ -101,7 +125,7 @@ pub fn load_shader(
             source: wgpu::ShaderSource::Glsl {
                 shader: std::borrow::Cow::Borrowed(fs_text),
                 stage: naga::ShaderStage::Fragment,
-                defines: &[],
+                defines,
             }
         }
     );
@@ -110,4 +134,69 @@ pub fn load_shader(
         vs_module,
         fs_module
     }
-}
\ No newline at end of file
+}
+
+/// 加载 WGSL 着色器
+pub fn load_shader_wgsl(
+    device: &wgpu::Device,
+    vs_text: &str,
+    fs_text: &str,
+    vs_label: &str,
+    fs_label: &str,
+) -> Shader {
+    let vs_module = device.create_shader_module(
+        wgpu::ShaderModuleDescriptor {
+            label: Some(vs_label),
+            source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Borrowed(vs_text)),
+        }
+    );
+
+    let fs_module = device.create_shader_module(
+        wgpu::ShaderModuleDescriptor {
+            label: Some(fs_label),
+            source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Borrowed(fs_text)),
+        }
+    );
+
+    Shader {
+        vs_module,
+        fs_module
+    }
+}
+
+/// WGSL 没有预处理器, 宏以布尔常量的形式拼接到源码头部
+/// * `all`
+///   * 着色器声明的全部宏名称, 未启用的宏生成 false
+/// * `defines`
+///   * 启用的 (宏名称, 宏值) 列表
+pub fn wgsl_defines(all: &[&str], defines: &[(&str, &str)]) -> String {
+    let mut header = String::new();
+    all.iter().for_each(|name| {
+        let enabled = defines.iter().any(|(key, _)| key == name);
+        header.push_str(&format!("const {}: bool = {};\n", name, enabled));
+    });
+    header
+}
+
+/// 从源码文本加载着色器 - 按语言选择加载方式
+/// * `all`
+///   * 着色器声明的全部宏名称, WGSL 需要据此生成常量头
+pub fn load_shader_source(
+    device: &wgpu::Device,
+    language: EShaderLanguage,
+    vs_text: &str,
+    fs_text: &str,
+    label: &str,
+    all: &[&str],
+    defines: &[(&str, &str)],
+) -> Shader {
+    match language {
+        EShaderLanguage::Glsl => load_shader_with_defines(device, vs_text, fs_text, label, label, defines),
+        EShaderLanguage::Wgsl => {
+            let header = wgsl_defines(all, defines);
+            let vs = header.clone() + vs_text;
+            let fs = header + fs_text;
+            load_shader_wgsl(device, &vs, &fs, label, label)
+        },
+    }
+}
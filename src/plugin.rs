@@ -0,0 +1,144 @@
+//! pi_world 接入
+//! * 实体同时挂载 PostprocessEffect, PostprocessSource, PostprocessResult 才参与后处理; 缺少任一组件的实体被跳过
+//! * Update 阶段 calc, 结果写入 PostprocessResult; 中间过程在 PostUpdate 阶段提交
+//! * 同时挂载 PostprocessFinalTarget 与 PostprocessFinalDraw 的实体, 在 PostUpdate 阶段更新 PostprocessFinalDraw, 由调用方在自己的 RenderPass 中 draw
+//! * 系统不插入任何组件 - PostprocessResult (可用 Default), PostprocessFinalDraw 均需调用方挂载
+
+use pi_assets::mgr::AssetMgr;
+use pi_render::{
+    components::view::target_alloc::{SafeAtlasAllocator, TargetType},
+    rhi::{device::RenderDevice, pipeline::RenderPipeline, asset::RenderRes, RenderQueue},
+    renderer::pipeline::DepthStencilState,
+};
+use pi_share::Share;
+use pi_world::prelude::*;
+
+use crate::{
+    postprocess::PostProcess,
+    error::EPostprocessError,
+    image_effect::{PostProcessDraw, SingleImageEffectResource},
+    temprory_render_target::PostprocessTexture,
+    IDENTITY_MATRIX,
+};
+
+/// 后处理共享上下文 - 单例资源
+pub struct PostprocessContext {
+    pub device: RenderDevice,
+    pub queue: RenderQueue,
+    pub safeatlas: SafeAtlasAllocator,
+    pub resources: Share<SingleImageEffectResource>,
+    pub pipelines: Share<AssetMgr<RenderRes<RenderPipeline>>>,
+    pub target_type: TargetType,
+    pub target_format: wgpu::TextureFormat,
+    /// 本帧间隔时间 ms - 调用方每帧更新
+    pub delta_time: u64,
+}
+
+/// 效果参数
+#[derive(Component, Default)]
+pub struct PostprocessEffect(pub PostProcess);
+
+/// 源内容 - None 时跳过处理
+#[derive(Component, Default)]
+pub struct PostprocessSource(pub Option<PostprocessTexture>);
+
+/// calc 结果 - 需调用方以 Default 挂载, 系统只更新已有的组件
+#[derive(Component, Default)]
+pub struct PostprocessResult {
+    /// 中间过程 - PostUpdate 阶段提交
+    pub draws: Vec<PostProcessDraw>,
+    /// 交给 draw_final 的纹理
+    pub texture: Option<PostprocessTexture>,
+    pub error: Option<EPostprocessError>,
+}
+
+/// 最终绘制参数 - 对应 draw_final 参数
+#[derive(Component)]
+pub struct PostprocessFinalTarget {
+    pub matrix: [f32; 16],
+    pub depth: f32,
+    pub target_size: (u32, u32),
+    pub color_state: wgpu::ColorTargetState,
+    pub depth_stencil: Option<DepthStencilState>,
+    pub viewport: Option<(f32, f32, f32, f32)>,
+    pub scissor: Option<(u32, u32, u32, u32)>,
+}
+impl PostprocessFinalTarget {
+    pub fn new(target_size: (u32, u32), color_state: wgpu::ColorTargetState) -> Self {
+        Self { matrix: IDENTITY_MATRIX, depth: 0., target_size, color_state, depth_stencil: None, viewport: None, scissor: None }
+    }
+}
+
+/// 最终绘制 - 调用方在 RenderPass 中 draw(None, Some(renderpass))
+/// * 需与 PostprocessFinalTarget 一同挂载, 系统只更新已有的组件
+#[derive(Component, Default)]
+pub struct PostprocessFinalDraw(pub Option<PostProcessDraw>);
+
+pub fn sys_postprocess_calc(
+    mut items: Query<(&mut PostprocessEffect, &PostprocessSource, &mut PostprocessResult)>,
+    context: SingleRes<PostprocessContext>,
+) {
+    items.iter_mut().for_each(|(mut effect, source, mut result)| {
+        result.draws.clear();
+        result.texture = None;
+        result.error = None;
+        if let Some(src) = &source.0 {
+            match effect.0.calc(
+                context.delta_time, &context.device, &context.queue, src.clone(), (src.use_w(), src.use_h()),
+                &context.safeatlas, &context.resources, &context.pipelines, context.target_type, context.target_format
+            ) {
+                Ok((draws, texture)) => {
+                    result.draws = draws;
+                    result.texture = Some(texture);
+                },
+                Err(err) => result.error = Some(err),
+            }
+        }
+    });
+}
+
+pub fn sys_postprocess_final(
+    mut items: Query<(&PostprocessEffect, &PostprocessResult, &PostprocessFinalTarget, &mut PostprocessFinalDraw)>,
+    context: SingleRes<PostprocessContext>,
+) {
+    items.iter_mut().for_each(|(effect, result, target, mut finaldraw)| {
+        finaldraw.0 = if let Some(texture) = &result.texture {
+            effect.0.draw_final(
+                &context.device, &context.queue, &target.matrix, target.depth, &context.safeatlas, texture, target.target_size,
+                &context.resources, &context.pipelines, target.color_state.clone(), target.depth_stencil.clone(),
+                context.target_type, context.target_format, target.viewport, target.scissor
+            )
+        } else {
+            None
+        };
+    });
+}
+
+/// 提交所有实体的中间过程
+pub fn sys_postprocess_draw_front(
+    items: Query<(&PostprocessEffect, &PostprocessResult)>,
+    context: SingleRes<PostprocessContext>,
+) {
+    let mut encoder = context.device.wgpu_device().create_command_encoder(&wgpu::CommandEncoderDescriptor { label: Some("PostprocessDrawFront") });
+    let mut count = 0;
+    items.iter().for_each(|(effect, result)| {
+        if result.draws.len() > 0 {
+            effect.0.draw_front(&mut encoder, &result.draws);
+            count += 1;
+        }
+    });
+    if count > 0 {
+        context.queue.submit(std::iter::once(encoder.finish()));
+    }
+}
+
+/// 注册后处理系统 - 需先插入 PostprocessContext 单例
+/// * final 依赖 calc 的结果, 放在其后的 PostUpdate 阶段
+pub struct PluginPostprocess;
+impl Plugin for PluginPostprocess {
+    fn build(&self, app: &mut App) {
+        app.add_system(Update, sys_postprocess_calc);
+        app.add_system(PostUpdate, sys_postprocess_final);
+        app.add_system(PostUpdate, sys_postprocess_draw_front);
+    }
+}
@@ -16,10 +16,12 @@ use pi_share::Share;
 
 use crate::{
     effect::*,
-    temprory_render_target::PostprocessTexture,
-    renderer::{ bloom_dual::bloom_dual_render, horizon_glitch::{horizon_glitch_render, horizon_glitch_render_calc}, blur_gauss::blur_gauss_render},
-    error::EPostprocessError,
+    temprory_render_target::{PostprocessTexture, TempTargetPool},
+    renderer::{ bloom_dual::bloom_dual_render, horizon_glitch::{horizon_glitch_render, horizon_glitch_render_calc}, blur_gauss::blur_gauss_render, drop_shadow::drop_shadow_render, glow::glow_render, outline::outline_render, jump_flood::jump_flood_render},
+    error::{EPostprocessError, EPostprocessErrorPolicy},
     image_effect::*,
+    stencil::PostprocessStencil,
+    time::PostprocessTime,
     material::create_default_target,
     geometry::IDENTITY_MATRIX, postprocess_flags::EPostprocessRenderType, SimpleRenderExtendsData
 };
@@ -48,10 +50,35 @@ pub struct PostProcess {
     pub horizon_glitch:     Option<HorizonGlitch>,
     pub image_mask:         Option<ImageMask>,
     pub clip_sdf:           Option<ClipSdf>,
+    pub drop_shadow:        Option<DropShadow>,
+    pub glow:               Option<Glow>,
+    pub outline:            Option<Outline>,
+    /// 输出源内容 alpha 的有向距离场
+    pub jump_flood:         Option<JumpFloodSdf>,
+    pub chromatic_aberration: Option<ChromaticAberration>,
+    pub film_grain:         Option<FilmGrain>,
+    pub pixelate:           Option<Pixelate>,
+
+    /// 场景深度 - 深度相关效果在其他效果之前处理, 深度纹理区域需与源内容(expand_bounds 时为扩展后区域)对应
+    pub depth:              Option<DepthInput>,
+    pub depth_fog:          Option<DepthFog>,
+    pub blur_bokeh_depth:   Option<BlurBokehDepth>,
 
     pub flags:              Vec<EPostprocessRenderType>,
     /// 源内容是否为预乘内容
     pub src_preimultiplied: bool,
+    /// * 是否按效果链的采样范围扩展输出区域 - 模糊, 泛光等效果的扩散内容不被源区域裁掉
+    /// * calc 先将源内容拷贝到四周留白的目标中, 偏移由 expanded_offset 获取
+    pub expand_bounds:      bool,
+    expanded_offset:        (u32, u32),
+    /// 最终绘制的模板设置 - 写入裁剪蒙版, 或限制在指定参考值区域内绘制
+    pub stencil:            Option<PostprocessStencil>,
+    /// 效果渲染失败时的处理方式
+    pub error_policy:       EPostprocessErrorPolicy,
+    /// 效果时间 - 暂停, 缩放, 设置绝对时间
+    pub time:               PostprocessTime,
+    /// draw_final 的输入是否为预乘内容 - calc 时确定
+    final_src_premultiplied: bool,
     horizon_glitch_instance:Option<RenderVertices>,
 
     pub(crate) renderer_copy: Option<CopyIntensityRenderer>,
@@ -68,6 +95,17 @@ pub struct PostProcess {
     pub(crate) renderer_horizon_glitch: Option<HorizonGlitchRenderer>,
     pub(crate) renderer_image_mask: Option<ImageMaskRenderer>,
     pub(crate) renderer_clip_sdf: Option<ClipSdfRenderer>,
+    pub(crate) renderer_depth_fog: Option<DepthFogRenderer>,
+    pub(crate) renderer_blur_bokeh_depth: Option<BlurBokehDepthRenderer>,
+    pub(crate) renderer_drop_shadow: Option<DropShadowRenderer>,
+    pub(crate) renderer_glow: Option<GlowRenderer>,
+    pub(crate) renderer_outline: Option<OutlineRenderer>,
+    pub(crate) renderer_jump_flood: Option<JumpFloodRenderer>,
+    pub(crate) renderer_chromatic_aberration: Option<ChromaticAberrationRenderer>,
+    pub(crate) renderer_film_grain: Option<FilmGrainRenderer>,
+    pub(crate) renderer_pixelate: Option<PixelateRenderer>,
+    /// 扩展输出区域 - (清空留白, 拷贝源内容)
+    pub(crate) renderer_expand: Option<(CopyIntensityRenderer, CopyIntensityRenderer)>,
 }
 
 impl Default for PostProcess {
@@ -95,9 +133,26 @@ impl Default for PostProcess {
             horizon_glitch:     None,
             image_mask:         None,
             clip_sdf:           None,
+            drop_shadow:        None,
+            glow:               None,
+            outline:            None,
+            jump_flood:         None,
+            chromatic_aberration: None,
+            film_grain:         None,
+            pixelate:           None,
+
+            depth:              None,
+            depth_fog:          None,
+            blur_bokeh_depth:   None,
 
             flags:              vec![],
             src_preimultiplied:  true,
+            expand_bounds:      false,
+            expanded_offset:    (0, 0),
+            stencil:            None,
+            error_policy:       EPostprocessErrorPolicy::Abort,
+            time:               PostprocessTime::default(),
+            final_src_premultiplied: true,
             horizon_glitch_instance: None,
             
             renderer_copy: None,
@@ -114,6 +169,16 @@ impl Default for PostProcess {
             renderer_horizon_glitch: None,
             renderer_image_mask: None,
             renderer_clip_sdf: None,
+            renderer_depth_fog: None,
+            renderer_blur_bokeh_depth: None,
+            renderer_drop_shadow: None,
+            renderer_glow: None,
+            renderer_outline: None,
+            renderer_jump_flood: None,
+            renderer_chromatic_aberration: None,
+            renderer_film_grain: None,
+            renderer_pixelate: None,
+            renderer_expand: None,
         }
     }
 }
@@ -143,6 +208,46 @@ pub struct TempResult {
 //     }
 // }
 
+/// 局部更新缓存 - 保留上次 calc_region 各效果的中间结果, 下次只重绘受影响的区域
+/// * 效果参数变化后需调用 invalidate, 下次 calc_region 整体重绘
+#[derive(Default)]
+pub struct PostprocessRegionCache {
+    flags: Vec<EPostprocessRenderType>,
+    size: (u32, u32),
+    targets: Vec<PostprocessTexture>,
+    region: Option<(u32, u32, u32, u32)>,
+}
+impl PostprocessRegionCache {
+    pub fn invalidate(&mut self) {
+        self.targets.clear();
+    }
+    /// 上次 calc_region 后最终结果需要更新的区域 - 相对结果纹理使用区域 (x, y, w, h); None 表示无需更新
+    pub fn region(&self) -> Option<(u32, u32, u32, u32)> {
+        self.region
+    }
+    fn is_valid(&self, flags: &[EPostprocessRenderType], src: &PostprocessTexture) -> bool {
+        self.targets.len() + 1 == flags.len()
+            && self.flags.as_slice() == flags
+            && self.size == (src.use_w(), src.use_h())
+            && !self.targets.iter().any(|item| item.same_texture(src))
+    }
+}
+
+/// 区域向外扩展 reach, 限制在 size 内; reach 为 None 时为整个区域, 空区域保持为空
+fn expand_region(region: (u32, u32, u32, u32), reach: Option<(u32, u32)>, size: (u32, u32)) -> (u32, u32, u32, u32) {
+    if region.2 == 0 || region.3 == 0 {
+        region
+    } else if let Some((rx, ry)) = reach {
+        let x0 = region.0.saturating_sub(rx).min(size.0);
+        let y0 = region.1.saturating_sub(ry).min(size.1);
+        let x1 = (region.0 + region.2).saturating_add(rx).min(size.0);
+        let y1 = (region.1 + region.3).saturating_add(ry).min(size.1);
+        (x0, y0, x1.max(x0) - x0, y1.max(y0) - y0)
+    } else {
+        (0, 0, size.0, size.1)
+    }
+}
+
 /// * 处理渲染逻辑
 ///   * 设置对应效果数据
 ///   * 调用 calc 预计算渲染所需数据
@@ -155,11 +260,12 @@ pub struct TempResult {
 impl PostProcess {
     /// 绘制前计算和准备
     /// * `delta_time`
-    ///   * 距离上次调用的间隔时间 ms
+    ///   * 距离上次调用的间隔时间 ms - 经 time 的暂停, 缩放后推进效果时间
     /// * `target`
     ///   * 最终结果的 ColorTarget
     /// * `depth_stencil`
     ///   * 最终结果的 DepthStencil
+    /// * 只修改自身状态, 共享的 resources, pipelines 可供多个 PostProcess 在不同线程同时 calc, 见 calc_batch
     pub fn calc(
         &mut self,
         delta_time: u64,
@@ -176,11 +282,11 @@ impl PostProcess {
         if self.renderer_copy.is_none() {
             self.renderer_copy = Some(CopyIntensityRenderer { param: CopyIntensity::default(), uniform: resources.uniform_buffer() })
         }
-        self.check(delta_time, true, device, queue, resources);
+        self.check(delta_time, true, device, queue, resources)?;
 
         // let matrix: &[f32] = &IDENTITY_MATRIX;
 
-        let drawlist: Vec<PostProcessDraw> = vec![];
+        let mut drawlist: Vec<PostProcessDraw> = vec![];
         
         let src_premultiplied: bool = self.src_preimultiplied;
         // if src.use_w() != dst_size.0 || src.use_h() != dst_size.1 {
@@ -216,6 +322,26 @@ impl PostProcess {
         //     src = result;
         // }
 
+        let mut src = src;
+        self.expanded_offset = (0, 0);
+        if self.expand_bounds && self.flags.len() > 0 {
+            let size = (src.use_w(), src.use_h());
+            let padding = self.flags.iter().fold((0, 0), |(x, y), flag| {
+                let (rx, ry) = self.effect_reach(*flag, size).unwrap_or((0, 0));
+                (x + rx, y + ry)
+            });
+            if padding.0 > 0 || padding.1 > 0 {
+                if self.renderer_expand.is_none() {
+                    self.renderer_expand = Some((
+                        CopyIntensityRenderer { param: CopyIntensity::default(), uniform: resources.uniform_buffer() },
+                        CopyIntensityRenderer { param: CopyIntensity::default(), uniform: resources.uniform_buffer() },
+                    ));
+                }
+                src = self._draw_expand(device, queue, src, padding, safeatlas, resources, pipelines, target_type, target_format, src_premultiplied, &mut drawlist);
+                self.expanded_offset = padding;
+            }
+        }
+
         let result = self._draw_front(
             device, queue, src, &IDENTITY_MATRIX, safeatlas, resources, pipelines, target_type, target_format, src_premultiplied, drawlist
         );
@@ -223,6 +349,243 @@ impl PostProcess {
         result
         // println!("{:?}", self.flags);
     }
+    /// 并行计算多个互不相关的 PostProcess - 共享同一 SingleImageEffectResource 与管线缓存
+    /// * `batch`
+    ///   * (后处理, 源纹理) 列表, 同一 PostProcess 不能出现两次
+    /// * `workers`
+    ///   * 工作线程数, 批量按顺序均分到各线程; 为 0 或 1 时在当前线程顺序执行
+    /// * `return`
+    ///   * 与 batch 顺序一致的 calc 结果, draws 仍在提交线程按顺序 draw_front
+    pub fn calc_batch(
+        batch: &mut [(&mut PostProcess, PostprocessTexture)],
+        workers: usize,
+        delta_time: u64,
+        device: &RenderDevice,
+        queue: &RenderQueue,
+        safeatlas: &SafeAtlasAllocator,
+        resources: &SingleImageEffectResource,
+        pipelines: &Share<AssetMgr<RenderRes<RenderPipeline>>>,
+        target_type: TargetType,
+        target_format: wgpu::TextureFormat,
+    ) -> Vec<Result<(Vec<PostProcessDraw>, PostprocessTexture), EPostprocessError>> {
+        let calc = |(postprocess, src): &mut (&mut PostProcess, PostprocessTexture)| {
+            postprocess.calc(delta_time, device, queue, src.clone(), (src.use_w(), src.use_h()), safeatlas, resources, pipelines, target_type, target_format)
+        };
+        if workers <= 1 || batch.len() <= 1 {
+            return batch.iter_mut().map(calc).collect();
+        }
+
+        let calc = &calc;
+        let chunk = (batch.len() + workers - 1) / workers;
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = batch.chunks_mut(chunk).map(|items| {
+                scope.spawn(move || items.iter_mut().map(calc).collect::<Vec<_>>())
+            }).collect();
+            handles.into_iter().flat_map(|handle| handle.join().unwrap()).collect()
+        })
+    }
+    /// 将最终结果直接渲染到调用方提供的纹理区域 - 不经过图集分配, 也不需要 draw_final 的额外拷贝
+    /// * `dst`
+    ///   * 调用方持有的纹理视图 (ETextureViewUsage::Tex / Temp 等) 与使用区域, 格式取 dst.format
+    /// * `return`
+    ///   * Ok((draws, dst)) - draws 经 draw_front 执行后 dst 使用区域即为最终结果
+    ///   * 没有任何效果时返回 (空列表, src), 与 calc 一致
+    /// * 不处理 expand_bounds
+    pub fn calc_into(
+        &mut self,
+        delta_time: u64,
+        device: &RenderDevice,
+        queue: &RenderQueue,
+        src: PostprocessTexture,
+        dst: PostprocessTexture,
+        safeatlas: &SafeAtlasAllocator,
+        resources: &SingleImageEffectResource,
+        pipelines: &Share<AssetMgr<RenderRes<RenderPipeline>>>,
+        target_type: TargetType,
+        target_format: wgpu::TextureFormat,
+    ) -> Result<(Vec<PostProcessDraw>, PostprocessTexture), EPostprocessError> {
+        if self.renderer_copy.is_none() {
+            self.renderer_copy = Some(CopyIntensityRenderer { param: CopyIntensity::default(), uniform: resources.uniform_buffer() })
+        }
+        // 最后一个效果直接输出到 dst, 不追加 FinalCopyIntensity
+        self.check(delta_time, false, device, queue, resources)?;
+        self.expanded_offset = (0, 0);
+
+        let count = self.flags.len();
+        if count == 0 {
+            return Ok((vec![], src));
+        }
+
+        let (mut drawlist, source) = self._draw_front(
+            device, queue, src, &IDENTITY_MATRIX, safeatlas, resources, pipelines, target_type, target_format, self.src_preimultiplied, vec![]
+        )?;
+
+        let extends = if let Some(alpha) = self.alpha {
+            SimpleRenderExtendsData { alpha: alpha.a, depth: 0. }
+        } else {
+            SimpleRenderExtendsData { alpha: 1., depth: 0. }
+        };
+        let flag = self.flags[count - 1];
+        let src_premultiplied = self.final_src_premultiplied;
+        let dst_premultiply = self.src_preimultiplied;
+        let dst_size = (dst.use_w(), dst.use_h());
+        let mut temp_result = TempResult { target: Some(dst.clone()), finaldraw: None };
+        let mut result_premultiplied = dst_premultiply;
+        let mark = drawlist.len();
+        if let Err(err) = self._draw_single_simple(
+            device, queue,
+            &IDENTITY_MATRIX, extends, flag, safeatlas,
+            &source, ETarget::Temp(dst_size.0, dst_size.1),
+            &mut drawlist, resources, pipelines,
+            create_default_target(dst.format()), None, target_type, dst.format(), &mut temp_result, src_premultiplied, dst_premultiply
+        ) {
+            if self.error_policy == EPostprocessErrorPolicy::Abort {
+                return Err(err);
+            }
+            log::warn!("PostProcess skip {:?}: {:?}", flag, err);
+            // 跳过最后一个效果, 由下方拷贝将其输入写入 dst
+            drawlist.truncate(mark);
+            temp_result.target = Some(source.clone());
+            result_premultiplied = src_premultiplied;
+        }
+
+        // 自行分配目标的效果 (BloomDual) 结果不在 dst 中, 补一次拷贝
+        let result = temp_result.target.take().ok_or(EPostprocessError::AllocateFailed(flag))?;
+        if !result.same_texture(&dst) || result.get_rect() != dst.get_rect() {
+            let param = self.renderer_copy.as_ref().ok_or(EPostprocessError::MissingRenderer(EPostprocessRenderType::FinalCopyIntensity))?;
+            let draw = EffectCopy::ready(
+                param, resources, device, queue,
+                0, dst_size, &IDENTITY_MATRIX, 1., 0., &result, safeatlas, target_type, pipelines,
+                create_default_target(dst.format()), None, result.size_eq_2(&dst_size), result_premultiplied, dst_premultiply
+            ).ok_or(EPostprocessError::MissingEffectResource(EffectCopy::KEY))?;
+            drawlist.push(PostProcessDraw::Temp(dst.get_rect(), draw, dst.view.clone()));
+        }
+
+        Ok((drawlist, dst))
+    }
+    /// expand_bounds 生效时, 源内容在 calc 结果中的偏移 (x, y) - 结果四周各扩展该距离, 未扩展时为 (0, 0)
+    pub fn expanded_offset(&self) -> (u32, u32) {
+        self.expanded_offset
+    }
+    /// 局部更新的 calc - 源内容只有部分区域变化时, 各效果只重绘受该区域影响的部分
+    /// * `dirty`
+    ///   * 源内容变化区域 - 相对 src 使用区域 (x, y, w, h); None 表示整体变化
+    /// * `cache`
+    ///   * 上次的中间结果; 效果组合, 源尺寸变化或含无法局部更新的效果时整体重绘
+    /// * 返回后 cache.region() 为最终效果 (draw_final) 需更新的区域
+    /// * 不处理 expand_bounds; 效果失败时不按 error_policy 跳过, 直接返回错误并使 cache 失效
+    pub fn calc_region(
+        &mut self,
+        delta_time: u64,
+        device: &RenderDevice,
+        queue: &RenderQueue,
+        src: PostprocessTexture,
+        dirty: Option<(u32, u32, u32, u32)>,
+        cache: &mut PostprocessRegionCache,
+        safeatlas: &SafeAtlasAllocator,
+        resources: &SingleImageEffectResource,
+        pipelines: &Share<AssetMgr<RenderRes<RenderPipeline>>>,
+        target_type: TargetType,
+        target_format: wgpu::TextureFormat,
+    ) -> Result<(Vec<PostProcessDraw>, PostprocessTexture), EPostprocessError> {
+        if self.renderer_copy.is_none() {
+            self.renderer_copy = Some(CopyIntensityRenderer { param: CopyIntensity::default(), uniform: resources.uniform_buffer() })
+        }
+        if let Err(err) = self.check(delta_time, true, device, queue, resources) {
+            cache.invalidate();
+            return Err(err);
+        }
+        self.expanded_offset = (0, 0);
+
+        let count = self.flags.len();
+        let size = (src.use_w(), src.use_h());
+        let full = (0, 0, size.0, size.1);
+
+        let mut region = match dirty {
+            Some(dirty) if cache.is_valid(&self.flags, &src) && self.flags[0..count - 1].iter().all(|flag| Self::region_capable(*flag)) => {
+                expand_region(dirty, Some((0, 0)), size)
+            },
+            _ => {
+                cache.invalidate();
+                full
+            },
+        };
+        let reuse = !cache.targets.is_empty();
+
+        let mut drawlist = vec![];
+        let mut source = src;
+        let target = ETarget::Temp(size.0, size.1);
+        let mut src_premultiplied = self.src_preimultiplied;
+        for i in 0..count.max(1) - 1 {
+            let flag = self.flags[i];
+            region = expand_region(region, self.effect_reach(flag, size), size);
+            if i > 0 { src_premultiplied = false; }
+
+            let mut temp_result = TempResult { target: None, finaldraw: None };
+            if reuse {
+                temp_result.target = Some(cache.targets[i].clone());
+            }
+            let start = drawlist.len();
+            if let Err(err) = self._draw_single_simple(
+                device, queue,
+                &IDENTITY_MATRIX, SimpleRenderExtendsData::default(), flag, safeatlas,
+                &source, target,
+                &mut drawlist, resources, pipelines,
+                create_default_target(target_format), None, target_type, target_format, &mut temp_result, src_premultiplied, false
+            ) {
+                cache.invalidate();
+                return Err(err);
+            }
+            if reuse {
+                let draws = drawlist.split_off(start);
+                drawlist.extend(draws.into_iter().map(|draw| draw.with_scissor(region)));
+            }
+            source = temp_result.target.ok_or(EPostprocessError::AllocateFailed(flag))?;
+            if !reuse {
+                cache.targets.push(source.clone());
+            }
+        }
+
+        if count > 0 {
+            region = expand_region(region, self.effect_reach(self.flags[count - 1], size), size);
+        }
+        self.final_src_premultiplied = if count > 1 { false } else { self.src_preimultiplied };
+        cache.flags = self.flags.clone();
+        cache.size = size;
+        cache.region = if region.2 > 0 && region.3 > 0 { Some(region) } else { None };
+
+        Ok((drawlist, source))
+    }
+    /// 效果的采样范围 - 像素, 源内容某点变化时结果中受影响的最远距离; None 表示可能影响整个区域
+    /// * `size`
+    ///   * 效果的渲染尺寸
+    pub fn effect_reach(&self, flag: EPostprocessRenderType, size: (u32, u32)) -> Option<(u32, u32)> {
+        match flag {
+            EPostprocessRenderType::ColorEffect => Some((0, 0)),
+            EPostprocessRenderType::CopyIntensity => Some((0, 0)),
+            EPostprocessRenderType::FinalCopyIntensity => Some((0, 0)),
+            EPostprocessRenderType::ImageMask => Some((0, 0)),
+            EPostprocessRenderType::ClipSdf => Some((0, 0)),
+            EPostprocessRenderType::DepthFog => Some((0, 0)),
+            EPostprocessRenderType::BlurDual => self.blur_dual.as_ref().and_then(|v| v.reach(size)),
+            EPostprocessRenderType::BlurDirect => self.blur_direct.as_ref().and_then(|v| v.reach(size)),
+            EPostprocessRenderType::BlurRadial => self.blur_radial.as_ref().and_then(|v| v.reach(size)),
+            EPostprocessRenderType::BlurBokeh => self.blur_bokeh.as_ref().and_then(|v| v.reach(size)),
+            EPostprocessRenderType::BlurBokehDepth => self.blur_bokeh_depth.as_ref().and_then(|v| v.reach(size)),
+            EPostprocessRenderType::BloomDual => self.bloom_dual.as_ref().and_then(|v| v.reach(size)),
+            EPostprocessRenderType::RadialWave => self.radial_wave.as_ref().and_then(|v| v.reach(size)),
+            EPostprocessRenderType::FilterSobel => self.filter_sobel.as_ref().and_then(|v| v.reach(size)),
+            EPostprocessRenderType::BlurGauss => self.blur_gauss.as_ref().and_then(|v| v.reach(size)),
+            EPostprocessRenderType::DropShadow => self.drop_shadow.as_ref().and_then(|v| v.reach(size)),
+            EPostprocessRenderType::Glow => self.glow.as_ref().and_then(|v| v.reach(size)),
+            EPostprocessRenderType::Outline => self.outline.as_ref().and_then(|v| v.reach(size)),
+            EPostprocessRenderType::JumpFlood => self.jump_flood.as_ref().and_then(|v| v.reach(size)),
+            EPostprocessRenderType::ChromaticAberration => self.chromatic_aberration.as_ref().and_then(|v| v.reach(size)),
+            EPostprocessRenderType::FilmGrain => self.film_grain.as_ref().and_then(|v| v.reach(size)),
+            EPostprocessRenderType::Pixelate => self.pixelate.as_ref().and_then(|v| v.reach(size)),
+            EPostprocessRenderType::HorizonGlitch => None,
+        }
+    }
     /// 对源内容进行后处理 - 最后一个效果的渲染在 draw_final 接口调用
     /// * `src`
     ///   * 源纹理内容
@@ -258,11 +621,16 @@ impl PostProcess {
     ///   * 渲染到目标时的网格变换
     /// * `depth`
     ///   * 渲染到目标时的深度值
+    /// * `viewport`
+    ///   * 渲染到目标的视口 (x, y, w, h) - 合成到共享 RenderPass 的子区域; None 沿用 RenderPass 当前设置
+    /// * `scissor`
+    ///   * 渲染到目标的裁剪区域 (x, y, w, h), 超出 target_size 的部分被裁掉; None 沿用 RenderPass 当前设置
+    /// * 视口, 裁剪区域, 模板参考值在 draw 后恢复为 RenderPass 默认值, 见 PostProcessDraw::Final
+    /// * 设置了 stencil 时, 模板状态应用到 depth_stencil, 参考值随 PostProcessDraw::Final 设置到 RenderPass
     /// * `return`
-    ///   * Ok(true) 执行成功
-    ///   * Ok(false) 当实际没有渲染时
-    ///       * Example: 模糊后处理, 模糊半径为 0 则认为不需要渲染过程, 应当直接使用 src, 返回 false
-    ///   * Err(String)
+    ///   * Some(PostProcessDraw::Final) 在调用方的 RenderPass 中 draw
+    ///   * None 当实际没有渲染时, 或最终效果失败且 error_policy 为 Abort
+    ///       * Example: 模糊后处理, 模糊半径为 0 则认为不需要渲染过程, 应当直接使用 src
     pub fn draw_final<'a>(
         &'a self,
         device: & RenderDevice,
@@ -278,7 +646,9 @@ impl PostProcess {
         depth_stencil: Option<DepthStencilState>,
         target_type: TargetType,
         target_format: wgpu::TextureFormat,
-    ) -> Option<DrawObj> {
+        viewport: Option<(f32, f32, f32, f32)>,
+        scissor: Option<(u32, u32, u32, u32)>,
+    ) -> Option<PostProcessDraw> {
 
         if matrix.len() == 16 {
             let count = self.flags.len();
@@ -291,12 +661,33 @@ impl PostProcess {
                 let flag = *self.flags.get(count - 1).unwrap();
                 let mut draws = vec![];
                 let mut tempresult = TempResult { target: None, finaldraw: None };
-                let src_premultiplied = if count == 1 { self.src_preimultiplied } else { false };
+                let src_premultiplied = self.final_src_premultiplied;
                 let dst_premultiply = self.src_preimultiplied;
-                self._draw_single_simple(device, queue, matrix, extends, flag, safeatlas, source, ETarget::Final(target_size.0, target_size.1), &mut draws, resources, pipelines, color_state, depth_stencil, target_type, target_format, &mut tempresult, src_premultiplied, dst_premultiply, true);
+                let (color_state, depth_stencil, stencil_reference) = if let Some(stencil) = &self.stencil {
+                    (stencil.color_state(color_state), stencil.depth_stencil(depth_stencil), Some(stencil.reference))
+                } else {
+                    (color_state, depth_stencil, None)
+                };
+                if let Err(err) = self._draw_single_simple(device, queue, matrix, extends, flag, safeatlas, source, ETarget::Final(target_size.0, target_size.1), &mut draws, resources, pipelines, color_state.clone(), depth_stencil.clone(), target_type, target_format, &mut tempresult, src_premultiplied, dst_premultiply) {
+                    log::warn!("PostProcess draw_final {:?}: {:?}", flag, err);
+                    // 跳过最终效果, 直接拷贝其输入
+                    if let (EPostprocessErrorPolicy::Skip, Some(param)) = (self.error_policy, &self.renderer_copy) {
+                        tempresult.finaldraw = EffectCopy::ready(
+                            param, resources, device, queue,
+                            0, target_size, matrix, extends.alpha, extends.depth, source, safeatlas, target_type, pipelines,
+                            color_state, depth_stencil, source.size_eq_2(&target_size), src_premultiplied, dst_premultiply
+                        );
+                    }
+                }
 
+                // 超出目标的裁剪区域无效, 限制在目标内
+                let scissor = scissor.map(|(x, y, w, h)| {
+                    let x = x.min(target_size.0);
+                    let y = y.min(target_size.1);
+                    (x, y, w.min(target_size.0 - x), h.min(target_size.1 - y))
+                });
                 if let Some(finaldraw) = tempresult.finaldraw {
-                    Some(finaldraw)
+                    Some(PostProcessDraw::Final(finaldraw, viewport, scissor, stencil_reference, target_size))
                 } else {
                     None
                 }
@@ -309,7 +700,7 @@ impl PostProcess {
     }
 
     fn _draw_front<'a>(
-        &self,
+        &mut self,
         device: &RenderDevice,
         queue: &RenderQueue,
         src: PostprocessTexture,
@@ -325,43 +716,140 @@ impl PostProcess {
         let count = self.flags.len();
 
         if count <= 1 {
+            self.final_src_premultiplied = src_premultiplied;
             Ok((drawlist, src))
         } else {
             let mut source = src;
+            // 全部效果失败被跳过时 source 仍为外部传入的 src
+            let mut source_is_src = true;
             let target = ETarget::Temp(source.use_w(), source.use_h());
             // let format = wgpu::TextureFormat::Rgba8UnormSrgb;
+            let mut pool = TempTargetPool::default();
             for i in 0..count-1 {
                 let flag = *self.flags.get(i).unwrap();
 
                 let mut temp_result = TempResult { target: None, finaldraw: None };
 
-                let mut result_use_once_innext = true;
-                if i < count - 1 {
-                    let next = *self.flags.get(i+1).unwrap();
-                    match next {
-                        EPostprocessRenderType::HorizonGlitch => result_use_once_innext = false,
-                        EPostprocessRenderType::BloomDual => result_use_once_innext = false,
-                        _ => result_use_once_innext = true,
-                    }
+                // 单目标输出的效果 复用已释放的中间结果; 结果的生命周期由 pool 跟踪
+                if Self::output_recyclable(flag) {
+                    temp_result.target = pool.acquire(target.size(), &source);
                 }
 
-                if i > 0 { src_premultiplied = false; }
-
-                self._draw_single_simple(
+                let mark = drawlist.len();
+                if let Err(err) = self._draw_single_simple(
                     device, queue,
                     &IDENTITY_MATRIX, SimpleRenderExtendsData::default(), flag, safeatlas,
                     &source, target,
                     &mut drawlist, resources, pipelines,
-                    create_default_target(target_format), None, target_type, target_format, &mut temp_result, src_premultiplied, false, result_use_once_innext
-                );
-                source = temp_result.target.unwrap();
-                temp_result.target = None;
+                    create_default_target(target_format), None, target_type, target_format, &mut temp_result, src_premultiplied, false
+                ) {
+                    if self.error_policy == EPostprocessErrorPolicy::Abort {
+                        return Err(err);
+                    }
+                    log::warn!("PostProcess skip {:?}: {:?}", flag, err);
+                    drawlist.truncate(mark);
+                    if let Some(unused) = temp_result.target.take() {
+                        pool.release(unused);
+                    }
+                    continue;
+                }
+                let result = temp_result.target.take().ok_or(EPostprocessError::AllocateFailed(flag))?;
+                let last = std::mem::replace(&mut source, result);
+                // last 的最后一个读取者已记录; 外部传入的 src 不回收, 原地渲染的结果延续到下一个效果
+                if !source_is_src && !last.same_texture(&source) {
+                    pool.release(last);
+                }
+                source_is_src = false;
+                src_premultiplied = false;
             }
 
+            self.final_src_premultiplied = src_premultiplied;
             Ok((drawlist, source))
         }
     }
 
+    /// 效果的输出目标是否由 get_target 以 TempResult::target 分配 - 内部自行分配多个目标的效果不参与复用
+    fn output_recyclable(flag: EPostprocessRenderType) -> bool {
+        match flag {
+            EPostprocessRenderType::BloomDual => false,
+            EPostprocessRenderType::HorizonGlitch => false,
+            EPostprocessRenderType::BlurGauss => false,
+            EPostprocessRenderType::DropShadow => false,
+            EPostprocessRenderType::Glow => false,
+            EPostprocessRenderType::Outline => false,
+            EPostprocessRenderType::JumpFlood => false,
+            _ => true,
+        }
+    }
+
+    /// 将源内容拷贝到四周扩展 padding 的目标中 - 先整体清空为透明, 再拷贝到中间区域, 预乘状态保持不变
+    fn _draw_expand(
+        &self,
+        device: &RenderDevice,
+        queue: &RenderQueue,
+        src: PostprocessTexture,
+        padding: (u32, u32),
+        safeatlas: &SafeAtlasAllocator,
+        resources: &SingleImageEffectResource,
+        pipelines: &Share<AssetMgr<RenderRes<RenderPipeline>>>,
+        target_type: TargetType,
+        target_format: wgpu::TextureFormat,
+        src_premultiplied: bool,
+        drawlist: &mut Vec<PostProcessDraw>,
+    ) -> PostprocessTexture {
+        let (clear, place) = self.renderer_expand.as_ref().unwrap();
+        let src_size = (src.use_w(), src.use_h());
+        let dst_size = (src_size.0 + padding.0 * 2, src_size.1 + padding.1 * 2);
+        let result = EffectCopy::get_target(None, &src, dst_size, safeatlas, target_type, target_format);
+        let (x, y, _, _) = result.get_rect();
+
+        // alpha 为 0 且输出预乘, 写入透明黑
+        if let Some(draw) = EffectCopy::ready(
+            clear, resources, device, queue,
+            0, dst_size, &IDENTITY_MATRIX, 0., 0., &src, safeatlas, target_type, pipelines,
+            create_default_target(target_format), None, false, false, true
+        ) {
+            drawlist.push(PostProcessDraw::Temp(result.get_rect(), draw, result.view.clone()));
+        }
+        if let Some(draw) = EffectCopy::ready(
+            place, resources, device, queue,
+            0, src_size, &IDENTITY_MATRIX, 1., 0., &src, safeatlas, target_type, pipelines,
+            create_default_target(target_format), None, true, src_premultiplied, src_premultiplied
+        ) {
+            drawlist.push(PostProcessDraw::Temp((x + padding.0, y + padding.1, src_size.0, src_size.1), draw, result.view.clone()));
+        }
+
+        result
+    }
+
+    /// 效果能否局部重绘 - 需单目标输出且结果不随时间变化; 深度纹理的变化不在脏区域中体现
+    fn region_capable(flag: EPostprocessRenderType) -> bool {
+        match flag {
+            EPostprocessRenderType::BlurDual => false,
+            EPostprocessRenderType::DepthFog => false,
+            EPostprocessRenderType::BlurBokehDepth => false,
+            // 噪声随帧变化
+            EPostprocessRenderType::FilmGrain => false,
+            _ => Self::output_recyclable(flag),
+        }
+    }
+
+    /// 分配效果的输出目标 - 尺寸为 0 时无法分配
+    fn alloc_target(
+        flag: EPostprocessRenderType,
+        target: Option<PostprocessTexture>,
+        source: &PostprocessTexture,
+        dst_size: (u32, u32),
+        safeatlas: &SafeAtlasAllocator,
+        target_type: TargetType,
+        target_format: wgpu::TextureFormat,
+    ) -> Result<PostprocessTexture, EPostprocessError> {
+        if dst_size.0 == 0 || dst_size.1 == 0 {
+            return Err(EPostprocessError::AllocateFailed(flag));
+        }
+        Ok(EffectCopy::get_target(target, source, dst_size, safeatlas, target_type, target_format))
+    }
+
     fn _draw_single_simple<'a>(
         &'a self,
         device: & RenderDevice,
@@ -382,20 +870,19 @@ impl PostProcess {
         temp_result: &mut TempResult,
         mut src_premultiplied: bool,
         dst_premultiply: bool,
-        result_target_useonce: bool
-    ) {
+    ) -> Result<(), EPostprocessError> {
         let dst_size = target.size();
         let force_nearest_filter = source.size_eq_2(&dst_size);
         match flag {
             EPostprocessRenderType::ColorEffect => {
-                let param = self.renderer_coloreffect.as_ref().unwrap();
+                let param = self.renderer_coloreffect.as_ref().ok_or(EPostprocessError::MissingRenderer(flag))?;
                 match target {
                     ETarget::Temp(_, _) => {
-                        let result = EffectCopy::get_target(None, &source, dst_size, safeatlas, target_type, target_format, result_target_useonce); 
+                        let result = Self::alloc_target(flag, temp_result.target.take(), &source, dst_size, safeatlas, target_type, target_format)?; 
                         let draw = EffectColorEffect::ready(
                             param, resources, device, queue, 0, dst_size, &matrix, extends.alpha, extends.depth,
                             source, safeatlas, target_type, pipelines, color_state, depth_stencil, force_nearest_filter, src_premultiplied, dst_premultiply
-                        ).unwrap();
+                        ).ok_or(EPostprocessError::MissingEffectResource(EffectColorEffect::KEY))?;
                         let draw = PostProcessDraw::Temp(result.get_rect(), draw, result.view.clone() );
                         draws.push(draw);
                         temp_result.target = Some(result);
@@ -404,20 +891,20 @@ impl PostProcess {
                         let draw = EffectColorEffect::ready(
                             param, resources, device, queue, 0, dst_size, &matrix, extends.alpha, extends.depth,
                             source, safeatlas, target_type, pipelines, color_state, depth_stencil, force_nearest_filter, src_premultiplied, dst_premultiply
-                        ).unwrap();
+                        ).ok_or(EPostprocessError::MissingEffectResource(EffectColorEffect::KEY))?;
                         temp_result.finaldraw = Some(draw);
                     },
                 }
             },
             EPostprocessRenderType::BlurDirect => {
-                let param = self.renderer_blur_direct.as_ref().unwrap();
+                let param = self.renderer_blur_direct.as_ref().ok_or(EPostprocessError::MissingRenderer(flag))?;
                 match target {
                     ETarget::Temp(_, _) => {
-                        let result = EffectCopy::get_target(None, &source, dst_size, safeatlas, target_type, target_format, result_target_useonce); 
+                        let result = Self::alloc_target(flag, temp_result.target.take(), &source, dst_size, safeatlas, target_type, target_format)?; 
                         let draw = EffectBlurDirect::ready(
                             param, resources, device, queue,
                             0, dst_size, &matrix, 1., 1., source, safeatlas, target_type, pipelines, color_state, depth_stencil, src_premultiplied, dst_premultiply
-                        ).unwrap();
+                        ).ok_or(EPostprocessError::MissingEffectResource(EffectBlurDirect::KEY))?;
                         let draw = PostProcessDraw::Temp(result.get_rect(), draw, result.view.clone() );
                         draws.push(draw);
                         temp_result.target = Some(result);
@@ -426,20 +913,20 @@ impl PostProcess {
                         let draw = EffectBlurDirect::ready(
                             param, resources, device, queue,
                             0, dst_size, &matrix, 1., 1., source, safeatlas, target_type, pipelines, color_state, depth_stencil, src_premultiplied, dst_premultiply
-                        ).unwrap();
+                        ).ok_or(EPostprocessError::MissingEffectResource(EffectBlurDirect::KEY))?;
                         temp_result.finaldraw = Some(draw);
                     },
                 }
             },
             EPostprocessRenderType::BlurRadial => {
-                let param = self.renderer_blur_radial.as_ref().unwrap();
+                let param = self.renderer_blur_radial.as_ref().ok_or(EPostprocessError::MissingRenderer(flag))?;
                 match target {
                     ETarget::Temp(_, _) => {
-                        let result = EffectCopy::get_target(None, &source, dst_size, safeatlas, target_type, target_format, result_target_useonce); 
+                        let result = Self::alloc_target(flag, temp_result.target.take(), &source, dst_size, safeatlas, target_type, target_format)?; 
                         let draw = EffectBlurRadial::ready(
                             param, resources, device, queue,
                             0, dst_size, &matrix, extends.alpha, extends.depth, source, safeatlas, target_type, pipelines,  color_state, depth_stencil, src_premultiplied, dst_premultiply
-                        ).unwrap();
+                        ).ok_or(EPostprocessError::MissingEffectResource(EffectBlurRadial::KEY))?;
                         let draw = PostProcessDraw::Temp(result.get_rect(), draw, result.view.clone() );
                         draws.push(draw);
                         temp_result.target = Some(result);
@@ -448,20 +935,20 @@ impl PostProcess {
                         let draw = EffectBlurRadial::ready(
                             param, resources, device, queue,
                             0, dst_size, &matrix, extends.alpha, extends.depth, source, safeatlas, target_type, pipelines,  color_state, depth_stencil, src_premultiplied, dst_premultiply
-                        ).unwrap();
+                        ).ok_or(EPostprocessError::MissingEffectResource(EffectBlurRadial::KEY))?;
                         temp_result.finaldraw = Some(draw);
                     },
                 }
             },
             EPostprocessRenderType::BlurBokeh => {
-                let param = self.renderer_blur_bokeh.as_ref().unwrap();
+                let param = self.renderer_blur_bokeh.as_ref().ok_or(EPostprocessError::MissingRenderer(flag))?;
                 match target {
                     ETarget::Temp(_, _) => {
-                        let result = EffectCopy::get_target(None, &source, dst_size, safeatlas, target_type, target_format, result_target_useonce); 
+                        let result = Self::alloc_target(flag, temp_result.target.take(), &source, dst_size, safeatlas, target_type, target_format)?; 
                         let draw = EffectBlurBokeh::ready(
                             param, resources, device, queue,
                             0, dst_size, &matrix, extends.alpha, extends.depth, &source, safeatlas, target_type, pipelines, color_state, depth_stencil, src_premultiplied, dst_premultiply
-                        ).unwrap();
+                        ).ok_or(EPostprocessError::MissingEffectResource(EffectBlurBokeh::KEY))?;
                         let draw = PostProcessDraw::Temp(result.get_rect(), draw, result.view.clone() );
                         draws.push(draw);
                         temp_result.target = Some(result);
@@ -470,20 +957,64 @@ impl PostProcess {
                         let draw = EffectBlurBokeh::ready(
                             param, resources, device, queue,
                             0, dst_size, &matrix, extends.alpha, extends.depth, &source, safeatlas, target_type, pipelines, color_state, depth_stencil, src_premultiplied, dst_premultiply
-                        ).unwrap();
+                        ).ok_or(EPostprocessError::MissingEffectResource(EffectBlurBokeh::KEY))?;
+                        temp_result.finaldraw = Some(draw);
+                    },
+                }
+            },
+            EPostprocessRenderType::DepthFog => {
+                let param = self.renderer_depth_fog.as_ref().ok_or(EPostprocessError::MissingRenderer(flag))?;
+                match target {
+                    ETarget::Temp(_, _) => {
+                        let result = Self::alloc_target(flag, temp_result.target.take(), &source, dst_size, safeatlas, target_type, target_format)?; 
+                        let draw = EffectDepthFog::ready(
+                            param, resources, device, queue,
+                            0, dst_size, &matrix, extends.alpha, extends.depth, &source, safeatlas, target_type, pipelines, color_state, depth_stencil, src_premultiplied, dst_premultiply
+                        ).ok_or(EPostprocessError::MissingEffectResource(EffectDepthFog::KEY))?;
+                        let draw = PostProcessDraw::Temp(result.get_rect(), draw, result.view.clone() );
+                        draws.push(draw);
+                        temp_result.target = Some(result);
+                    },
+                    ETarget::Final(_, _) => {
+                        let draw = EffectDepthFog::ready(
+                            param, resources, device, queue,
+                            0, dst_size, &matrix, extends.alpha, extends.depth, &source, safeatlas, target_type, pipelines, color_state, depth_stencil, src_premultiplied, dst_premultiply
+                        ).ok_or(EPostprocessError::MissingEffectResource(EffectDepthFog::KEY))?;
+                        temp_result.finaldraw = Some(draw);
+                    },
+                }
+            },
+            EPostprocessRenderType::BlurBokehDepth => {
+                let param = self.renderer_blur_bokeh_depth.as_ref().ok_or(EPostprocessError::MissingRenderer(flag))?;
+                match target {
+                    ETarget::Temp(_, _) => {
+                        let result = Self::alloc_target(flag, temp_result.target.take(), &source, dst_size, safeatlas, target_type, target_format)?; 
+                        let draw = EffectBlurBokehDepth::ready(
+                            param, resources, device, queue,
+                            0, dst_size, &matrix, extends.alpha, extends.depth, &source, safeatlas, target_type, pipelines, color_state, depth_stencil, src_premultiplied, dst_premultiply
+                        ).ok_or(EPostprocessError::MissingEffectResource(EffectBlurBokehDepth::KEY))?;
+                        let draw = PostProcessDraw::Temp(result.get_rect(), draw, result.view.clone() );
+                        draws.push(draw);
+                        temp_result.target = Some(result);
+                    },
+                    ETarget::Final(_, _) => {
+                        let draw = EffectBlurBokehDepth::ready(
+                            param, resources, device, queue,
+                            0, dst_size, &matrix, extends.alpha, extends.depth, &source, safeatlas, target_type, pipelines, color_state, depth_stencil, src_premultiplied, dst_premultiply
+                        ).ok_or(EPostprocessError::MissingEffectResource(EffectBlurBokehDepth::KEY))?;
                         temp_result.finaldraw = Some(draw);
                     },
                 }
             },
             EPostprocessRenderType::RadialWave => {
-                let param = self.renderer_radial_wave.as_ref().unwrap();
+                let param = self.renderer_radial_wave.as_ref().ok_or(EPostprocessError::MissingRenderer(flag))?;
                 match target {
                     ETarget::Temp(_, _) => {
-                        let result = EffectCopy::get_target(None, &source, dst_size, safeatlas, target_type, target_format, result_target_useonce); 
+                        let result = Self::alloc_target(flag, temp_result.target.take(), &source, dst_size, safeatlas, target_type, target_format)?; 
                         let draw = EffectRadialWave::ready(
                             param, resources, device, queue,
                             0, dst_size, &matrix,  extends.alpha, extends.depth, source, safeatlas, target_type, pipelines, color_state, depth_stencil, src_premultiplied, dst_premultiply
-                        ).unwrap();
+                        ).ok_or(EPostprocessError::MissingEffectResource(EffectRadialWave::KEY))?;
                         let draw = PostProcessDraw::Temp(result.get_rect(), draw, result.view.clone() );
                         draws.push(draw);
                         temp_result.target = Some(result);
@@ -492,20 +1023,86 @@ impl PostProcess {
                         let draw = EffectRadialWave::ready(
                             param, resources, device, queue,
                             0, dst_size, &matrix,  extends.alpha, extends.depth, source, safeatlas, target_type, pipelines, color_state, depth_stencil, src_premultiplied, dst_premultiply
-                        ).unwrap();
+                        ).ok_or(EPostprocessError::MissingEffectResource(EffectRadialWave::KEY))?;
+                        temp_result.finaldraw = Some(draw);
+                    },
+                }
+            },
+            EPostprocessRenderType::ChromaticAberration => {
+                let param = self.renderer_chromatic_aberration.as_ref().ok_or(EPostprocessError::MissingRenderer(flag))?;
+                match target {
+                    ETarget::Temp(_, _) => {
+                        let result = Self::alloc_target(flag, temp_result.target.take(), &source, dst_size, safeatlas, target_type, target_format)?; 
+                        let draw = EffectChromaticAberration::ready(
+                            param, resources, device, queue,
+                            0, dst_size, &matrix,  extends.alpha, extends.depth, source, safeatlas, target_type, pipelines, color_state, depth_stencil, src_premultiplied, dst_premultiply
+                        ).ok_or(EPostprocessError::MissingEffectResource(EffectChromaticAberration::KEY))?;
+                        let draw = PostProcessDraw::Temp(result.get_rect(), draw, result.view.clone() );
+                        draws.push(draw);
+                        temp_result.target = Some(result);
+                    },
+                    ETarget::Final(_, _) => {
+                        let draw = EffectChromaticAberration::ready(
+                            param, resources, device, queue,
+                            0, dst_size, &matrix,  extends.alpha, extends.depth, source, safeatlas, target_type, pipelines, color_state, depth_stencil, src_premultiplied, dst_premultiply
+                        ).ok_or(EPostprocessError::MissingEffectResource(EffectChromaticAberration::KEY))?;
+                        temp_result.finaldraw = Some(draw);
+                    },
+                }
+            },
+            EPostprocessRenderType::FilmGrain => {
+                let param = self.renderer_film_grain.as_ref().ok_or(EPostprocessError::MissingRenderer(flag))?;
+                match target {
+                    ETarget::Temp(_, _) => {
+                        let result = Self::alloc_target(flag, temp_result.target.take(), &source, dst_size, safeatlas, target_type, target_format)?; 
+                        let draw = EffectFilmGrain::ready(
+                            param, resources, device, queue,
+                            0, dst_size, &matrix,  extends.alpha, extends.depth, source, safeatlas, target_type, pipelines, color_state, depth_stencil, src_premultiplied, dst_premultiply
+                        ).ok_or(EPostprocessError::MissingEffectResource(EffectFilmGrain::KEY))?;
+                        let draw = PostProcessDraw::Temp(result.get_rect(), draw, result.view.clone() );
+                        draws.push(draw);
+                        temp_result.target = Some(result);
+                    },
+                    ETarget::Final(_, _) => {
+                        let draw = EffectFilmGrain::ready(
+                            param, resources, device, queue,
+                            0, dst_size, &matrix,  extends.alpha, extends.depth, source, safeatlas, target_type, pipelines, color_state, depth_stencil, src_premultiplied, dst_premultiply
+                        ).ok_or(EPostprocessError::MissingEffectResource(EffectFilmGrain::KEY))?;
+                        temp_result.finaldraw = Some(draw);
+                    },
+                }
+            },
+            EPostprocessRenderType::Pixelate => {
+                let param = self.renderer_pixelate.as_ref().ok_or(EPostprocessError::MissingRenderer(flag))?;
+                match target {
+                    ETarget::Temp(_, _) => {
+                        let result = Self::alloc_target(flag, temp_result.target.take(), &source, dst_size, safeatlas, target_type, target_format)?; 
+                        let draw = EffectPixelate::ready(
+                            param, resources, device, queue,
+                            0, dst_size, &matrix,  extends.alpha, extends.depth, source, safeatlas, target_type, pipelines, color_state, depth_stencil, src_premultiplied, dst_premultiply
+                        ).ok_or(EPostprocessError::MissingEffectResource(EffectPixelate::KEY))?;
+                        let draw = PostProcessDraw::Temp(result.get_rect(), draw, result.view.clone() );
+                        draws.push(draw);
+                        temp_result.target = Some(result);
+                    },
+                    ETarget::Final(_, _) => {
+                        let draw = EffectPixelate::ready(
+                            param, resources, device, queue,
+                            0, dst_size, &matrix,  extends.alpha, extends.depth, source, safeatlas, target_type, pipelines, color_state, depth_stencil, src_premultiplied, dst_premultiply
+                        ).ok_or(EPostprocessError::MissingEffectResource(EffectPixelate::KEY))?;
                         temp_result.finaldraw = Some(draw);
                     },
                 }
             },
             EPostprocessRenderType::FilterSobel => {
-                let param = self.renderer_filter_sobel.as_ref().unwrap();
+                let param = self.renderer_filter_sobel.as_ref().ok_or(EPostprocessError::MissingRenderer(flag))?;
                 match target {
                     ETarget::Temp(_, _) => {
-                        let result = EffectCopy::get_target(None, &source, dst_size, safeatlas, target_type, target_format, result_target_useonce); 
+                        let result = Self::alloc_target(flag, temp_result.target.take(), &source, dst_size, safeatlas, target_type, target_format)?; 
                         let draw = EffectFilterSobel::ready(
                             param, resources, device, queue,
                             0, dst_size, &matrix,  extends.alpha, extends.depth, source, safeatlas, target_type, pipelines, color_state, depth_stencil, src_premultiplied, dst_premultiply
-                        ).unwrap();
+                        ).ok_or(EPostprocessError::MissingEffectResource(EffectFilterSobel::KEY))?;
                         let draw = PostProcessDraw::Temp(result.get_rect(), draw, result.view.clone() );
                         draws.push(draw);
                         temp_result.target = Some(result);
@@ -514,20 +1111,20 @@ impl PostProcess {
                         let draw = EffectFilterSobel::ready(
                             param, resources, device, queue,
                             0, dst_size, &matrix,  extends.alpha, extends.depth, source, safeatlas, target_type, pipelines, color_state, depth_stencil, src_premultiplied, dst_premultiply
-                        ).unwrap();
+                        ).ok_or(EPostprocessError::MissingEffectResource(EffectFilterSobel::KEY))?;
                         temp_result.finaldraw = Some(draw);
                     },
                 }
             },
             EPostprocessRenderType::CopyIntensity => {
-                let param = self.renderer_copyintensity.as_ref().unwrap();
+                let param = self.renderer_copyintensity.as_ref().ok_or(EPostprocessError::MissingRenderer(flag))?;
                 match target {
                     ETarget::Temp(_, _) => {
-                        let result = EffectCopy::get_target(None, &source, dst_size, safeatlas, target_type, target_format, result_target_useonce); 
+                        let result = Self::alloc_target(flag, temp_result.target.take(), &source, dst_size, safeatlas, target_type, target_format)?; 
                         let draw = EffectCopy::ready(
                             param, resources, device, queue,
                             0, dst_size, &matrix,  extends.alpha, extends.depth, source, safeatlas, target_type, pipelines, color_state, depth_stencil, force_nearest_filter, src_premultiplied, dst_premultiply
-                        ).unwrap();
+                        ).ok_or(EPostprocessError::MissingEffectResource(EffectCopy::KEY))?;
                         let draw = PostProcessDraw::Temp(result.get_rect(), draw, result.view.clone() );
                         draws.push(draw);
                         temp_result.target = Some(result);
@@ -536,20 +1133,20 @@ impl PostProcess {
                         let draw = EffectCopy::ready(
                             param, resources, device, queue,
                             0, dst_size, &matrix,  extends.alpha, extends.depth, source, safeatlas, target_type, pipelines, color_state, depth_stencil, force_nearest_filter, src_premultiplied, dst_premultiply
-                        ).unwrap();
+                        ).ok_or(EPostprocessError::MissingEffectResource(EffectCopy::KEY))?;
                         temp_result.finaldraw = Some(draw);
                     },
                 }
             },
             EPostprocessRenderType::FinalCopyIntensity => {
-                let param = self.renderer_copy.as_ref().unwrap();
+                let param = self.renderer_copy.as_ref().ok_or(EPostprocessError::MissingRenderer(flag))?;
                 match target {
                     ETarget::Temp(_, _) => {
-                        let result = EffectCopy::get_target(None, &source, dst_size, safeatlas, target_type, target_format, result_target_useonce); 
+                        let result = Self::alloc_target(flag, temp_result.target.take(), &source, dst_size, safeatlas, target_type, target_format)?; 
                         let draw = EffectCopy::ready(
                             param, resources, device, queue,
                             0, dst_size, &matrix,  extends.alpha, extends.depth, source, safeatlas, target_type, pipelines, color_state, depth_stencil, force_nearest_filter, src_premultiplied, dst_premultiply
-                        ).unwrap();
+                        ).ok_or(EPostprocessError::MissingEffectResource(EffectCopy::KEY))?;
                         let draw = PostProcessDraw::Temp(result.get_rect(), draw, result.view.clone() );
                         draws.push(draw);
                         temp_result.target = Some(result);
@@ -558,20 +1155,20 @@ impl PostProcess {
                         let draw = EffectCopy::ready(
                             param, resources, device, queue,
                             0, dst_size, &matrix,  extends.alpha, extends.depth, source, safeatlas, target_type, pipelines, color_state, depth_stencil, force_nearest_filter, src_premultiplied, dst_premultiply
-                        ).unwrap();
+                        ).ok_or(EPostprocessError::MissingEffectResource(EffectCopy::KEY))?;
                         temp_result.finaldraw = Some(draw);
                     },
                 }
             },
             EPostprocessRenderType::ImageMask => {
-                let param = self.renderer_image_mask.as_ref().unwrap();
+                let param = self.renderer_image_mask.as_ref().ok_or(EPostprocessError::MissingRenderer(flag))?;
                 match target {
                     ETarget::Temp(_, _) => {
-                        let result = EffectImageMask::get_target(None, &source, dst_size, safeatlas, target_type, target_format, result_target_useonce); 
+                        let result = Self::alloc_target(flag, temp_result.target.take(), &source, dst_size, safeatlas, target_type, target_format)?; 
                         let draw = EffectImageMask::ready(
                             param, resources, device, queue,
                             0, dst_size, &matrix,  extends.alpha, extends.depth, source, safeatlas, target_type, pipelines, color_state, depth_stencil, force_nearest_filter, src_premultiplied, dst_premultiply
-                        ).unwrap();
+                        ).ok_or(EPostprocessError::MissingEffectResource(EffectImageMask::KEY))?;
                         let draw = PostProcessDraw::Temp(result.get_rect(), draw, result.view.clone() );
                         draws.push(draw);
                         temp_result.target = Some(result);
@@ -580,20 +1177,20 @@ impl PostProcess {
                         let draw = EffectImageMask::ready(
                             param, resources, device, queue,
                             0, dst_size, &matrix,  extends.alpha, extends.depth, source, safeatlas, target_type, pipelines, color_state, depth_stencil, force_nearest_filter, src_premultiplied, dst_premultiply
-                        ).unwrap();
+                        ).ok_or(EPostprocessError::MissingEffectResource(EffectImageMask::KEY))?;
                         temp_result.finaldraw = Some(draw);
                     },
                 }
             },
             EPostprocessRenderType::ClipSdf => {
-                let param = self.renderer_clip_sdf.as_ref().unwrap();
+                let param = self.renderer_clip_sdf.as_ref().ok_or(EPostprocessError::MissingRenderer(flag))?;
                 match target {
                     ETarget::Temp(_, _) => {
-                        let result = EffectClipSdf::get_target(None, &source, dst_size, safeatlas, target_type, target_format, result_target_useonce); 
+                        let result = Self::alloc_target(flag, temp_result.target.take(), &source, dst_size, safeatlas, target_type, target_format)?; 
                         let draw = EffectClipSdf::ready(
                             param, resources, device, queue,
                             0, dst_size, &matrix,  extends.alpha, extends.depth, source, safeatlas, target_type, pipelines, color_state, depth_stencil, force_nearest_filter, src_premultiplied, dst_premultiply
-                        ).unwrap();
+                        ).ok_or(EPostprocessError::MissingEffectResource(EffectClipSdf::KEY))?;
                         let draw = PostProcessDraw::Temp(result.get_rect(), draw, result.view.clone() );
                         draws.push(draw);
                         temp_result.target = Some(result);
@@ -602,17 +1199,16 @@ impl PostProcess {
                         let draw = EffectClipSdf::ready(
                             param, resources, device, queue,
                             0, dst_size, &matrix,  extends.alpha, extends.depth, source, safeatlas, target_type, pipelines, color_state, depth_stencil, force_nearest_filter, src_premultiplied, dst_premultiply
-                        ).unwrap();
+                        ).ok_or(EPostprocessError::MissingEffectResource(EffectClipSdf::KEY))?;
                         temp_result.finaldraw = Some(draw);
                     },
                 }
             },
             EPostprocessRenderType::BlurDual => {
-                let param = self.renderer_blur_dual.as_ref().unwrap();
+                let param = self.renderer_blur_dual.as_ref().ok_or(EPostprocessError::MissingRenderer(flag))?;
                 match target {
                     ETarget::Temp(_, _) => {
                         let mut realiter = 0;
-                        let _blur_dual = self.blur_dual.as_ref().unwrap();
                         let fromw = dst_size.0;
                         let fromh = dst_size.1;
                         let mut tow = fromw;
@@ -623,7 +1219,7 @@ impl PostProcess {
                                 tow = tow / 2;
                                 toh = toh / 2;
                                 realiter += 1;
-                                let result = EffectCopy::get_target(None, &tempresult, (tow, toh), safeatlas, target_type, target_format, true); 
+                                let result = EffectCopy::get_target(None, &tempresult, (tow, toh), safeatlas, target_type, target_format); 
                                 let draw = EffectBlurDual::ready(
                                     param.downs.get(idx).unwrap(), resources, device, queue,
                                     0, (tow, toh),
@@ -632,7 +1228,7 @@ impl PostProcess {
                                     tempresult,
                                     safeatlas, target_type, pipelines,
                                     create_default_target(target_format), None, src_premultiplied, false
-                                ).unwrap();
+                                ).ok_or(EPostprocessError::MissingEffectResource(EffectBlurDual::KEY))?;
                                 
                                 let draw = PostProcessDraw::Temp(result.get_rect(), draw, result.view.clone() );
                                 draws.push(draw);
@@ -646,7 +1242,7 @@ impl PostProcess {
                             for idx in 1..realiter {
                                 tow = tow * 2;
                                 toh = toh * 2;
-                                let result = EffectCopy::get_target(None, &tempresult, (tow, toh), safeatlas, target_type, target_format, true); 
+                                let result = EffectCopy::get_target(None, &tempresult, (tow, toh), safeatlas, target_type, target_format); 
                                 let draw = EffectBlurDual::ready(
                                     param.ups.get(idx).unwrap(), resources, device, queue,
                                     0, (tow, toh),
@@ -655,7 +1251,7 @@ impl PostProcess {
                                     tempresult,
                                     safeatlas, target_type, pipelines,
                                     create_default_target(target_format), None, src_premultiplied, false
-                                ).unwrap();
+                                ).ok_or(EPostprocessError::MissingEffectResource(EffectBlurDual::KEY))?;
                                 let draw = PostProcessDraw::Temp(result.get_rect(), draw, result.view.clone() );
                                 draws.push(draw);
                                 tempresult = result;
@@ -668,7 +1264,9 @@ impl PostProcess {
                         tow = fromw;
                         toh = fromh;
                         let param = param.ups.get(0).unwrap();
-                        let result = EffectCopy::get_target(None, &tempresult, (tow, toh), safeatlas, target_type, target_format, true); 
+                        // 回收的目标需避开最后一次上采样的源
+                        let recycled = temp_result.target.take().filter(|item| !item.same_texture(&tempresult));
+                        let result = Self::alloc_target(flag, recycled, &tempresult, (tow, toh), safeatlas, target_type, target_format)?;
                         let draw = EffectBlurDual::ready(
                             param, resources, device, queue,
                             0, (tow, toh),
@@ -677,7 +1275,7 @@ impl PostProcess {
                             tempresult,
                             safeatlas, target_type, pipelines,
                             color_state, depth_stencil, src_premultiplied, dst_premultiply
-                        ).unwrap();
+                        ).ok_or(EPostprocessError::MissingEffectResource(EffectBlurDual::KEY))?;
                         let draw = PostProcessDraw::Temp(result.get_rect(), draw, result.view.clone() );
                         draws.push(draw);
                         tempresult = result;
@@ -685,64 +1283,133 @@ impl PostProcess {
                         temp_result.target = Some(tempresult);
                     },
                     _ => {
-                        return;
+                        return Ok(());
                     },
                 }
-                return;
+                return Ok(());
             },
             EPostprocessRenderType::BloomDual => {
-                let param = self.renderer_bloom_dual.as_ref().unwrap();
+                let param = self.renderer_bloom_dual.as_ref().ok_or(EPostprocessError::MissingRenderer(flag))?;
                 match target {
                     ETarget::Temp(_, _) => {
                         let result = bloom_dual_render(
                             param,
                             device, queue, matrix, extends,
                             safeatlas, source.clone(), draws, resources, pipelines, depth_stencil, target_type, target_format, src_premultiplied, dst_premultiply
-                        );
+                        )?;
                         temp_result.target = Some(result);
-                        return;
+                        return Ok(());
                     },
                     _ => {
-                        return;
+                        return Ok(());
                     },
                 }
             },
             EPostprocessRenderType::HorizonGlitch => {
-                let param = self.renderer_horizon_glitch.as_ref().unwrap();
+                let param = self.renderer_horizon_glitch.as_ref().ok_or(EPostprocessError::MissingRenderer(flag))?;
                 match target {
                     ETarget::Temp(_, _) => {
                         let result = horizon_glitch_render(
                             param,
                             device, queue, self.horizon_glitch_instance.clone(), matrix,
-                            safeatlas, source, None, draws, resources, pipelines, color_state, depth_stencil, target_type, target_format, src_premultiplied, dst_premultiply
-                        );
+                            safeatlas, source, temp_result.target.take(), draws, resources, pipelines, color_state, depth_stencil, target_type, target_format, src_premultiplied, dst_premultiply
+                        )?;
                         temp_result.target = Some(result);
-                        return;
+                        return Ok(());
                     },
                     _ => {
-                        return;
+                        return Ok(());
                     },
                 }
             },
             EPostprocessRenderType::BlurGauss => {
-                let (hparam, vparam) = self.renderer_blur_gauss.as_ref().unwrap();
+                let (hparam, vparam) = self.renderer_blur_gauss.as_ref().ok_or(EPostprocessError::MissingRenderer(flag))?;
                 match target {
                     ETarget::Temp(_, _) => {
                         let result = blur_gauss_render(
                             hparam, vparam,
                             device, queue, matrix,
-                            safeatlas, source, None, draws, resources, pipelines, color_state, depth_stencil, target_type, target_format, src_premultiplied, dst_premultiply
-                        );
+                            safeatlas, source, temp_result.target.take(), draws, resources, pipelines, color_state, depth_stencil, target_type, target_format, src_premultiplied, dst_premultiply
+                        )?;
+                        temp_result.target = Some(result);
+                        return Ok(());
+                    },
+                    _ => {
+                        return Ok(());
+                    },
+                }
+            },
+            EPostprocessRenderType::DropShadow => {
+                let param = self.renderer_drop_shadow.as_ref().ok_or(EPostprocessError::MissingRenderer(flag))?;
+                match target {
+                    ETarget::Temp(_, _) => {
+                        let result = drop_shadow_render(
+                            param,
+                            device, queue, matrix,
+                            safeatlas, source, temp_result.target.take(), draws, resources, pipelines, color_state, depth_stencil, target_type, target_format, src_premultiplied, dst_premultiply
+                        )?;
+                        temp_result.target = Some(result);
+                        return Ok(());
+                    },
+                    _ => {
+                        return Ok(());
+                    },
+                }
+            },
+            EPostprocessRenderType::Glow => {
+                let param = self.renderer_glow.as_ref().ok_or(EPostprocessError::MissingRenderer(flag))?;
+                match target {
+                    ETarget::Temp(_, _) => {
+                        let result = glow_render(
+                            param,
+                            device, queue, matrix,
+                            safeatlas, source, temp_result.target.take(), draws, resources, pipelines, color_state, depth_stencil, target_type, target_format, src_premultiplied, dst_premultiply
+                        )?;
+                        temp_result.target = Some(result);
+                        return Ok(());
+                    },
+                    _ => {
+                        return Ok(());
+                    },
+                }
+            },
+            EPostprocessRenderType::Outline => {
+                let param = self.renderer_outline.as_ref().ok_or(EPostprocessError::MissingRenderer(flag))?;
+                match target {
+                    ETarget::Temp(_, _) => {
+                        let result = outline_render(
+                            param,
+                            device, queue, matrix,
+                            safeatlas, source, temp_result.target.take(), draws, resources, pipelines, color_state, depth_stencil, target_type, target_format, src_premultiplied, dst_premultiply
+                        )?;
                         temp_result.target = Some(result);
-                        return;
+                        return Ok(());
                     },
                     _ => {
-                        return;
+                        return Ok(());
+                    },
+                }
+            },
+            EPostprocessRenderType::JumpFlood => {
+                let param = self.renderer_jump_flood.as_ref().ok_or(EPostprocessError::MissingRenderer(flag))?;
+                match target {
+                    ETarget::Temp(_, _) => {
+                        let result = jump_flood_render(
+                            param,
+                            device, queue, matrix,
+                            safeatlas, source, temp_result.target.take(), draws, resources, pipelines, color_state, depth_stencil, target_type, target_format, src_premultiplied, dst_premultiply
+                        )?;
+                        temp_result.target = Some(result);
+                        return Ok(());
+                    },
+                    _ => {
+                        return Ok(());
                     },
                 }
             },
         };
 
+        Ok(())
     }
 
     fn check(
@@ -752,8 +1419,9 @@ impl PostProcess {
         device: & RenderDevice,
         queue: & RenderQueue,
         resources: &SingleImageEffectResource,
-    ) {
+    ) -> Result<(), EPostprocessError> {
         self.flags.clear();
+        self.time.advance(delta_time as f64);
 
         // color
         let color_effect     = (self.hsb.is_some() && self.hsb.as_ref().unwrap().is_enabled()) 
@@ -772,12 +1440,43 @@ impl PostProcess {
         let filter_sobel     = self.filter_sobel.is_some() && self.filter_sobel.as_ref().unwrap().is_enabled();
         let horizon_glitch   = self.horizon_glitch.is_some() && self.horizon_glitch.as_ref().unwrap().is_enabled();
         let blur_gauss       = self.blur_gauss.is_some() && self.blur_gauss.as_ref().unwrap().is_enabled();
+        let drop_shadow      = self.drop_shadow.is_some() && self.drop_shadow.as_ref().unwrap().is_enabled();
+        let glow             = self.glow.is_some() && self.glow.as_ref().unwrap().is_enabled();
+        let outline          = self.outline.is_some() && self.outline.as_ref().unwrap().is_enabled();
+        let jump_flood       = self.jump_flood.is_some() && self.jump_flood.as_ref().unwrap().is_enabled();
+        let chromatic_aberration = self.chromatic_aberration.is_some() && self.chromatic_aberration.as_ref().unwrap().is_enabled();
+        let film_grain       = self.film_grain.is_some() && self.film_grain.as_ref().unwrap().is_enabled();
+        let pixelate         = self.pixelate.is_some() && self.pixelate.as_ref().unwrap().is_enabled();
         let image_mask       = self.image_mask.is_some();
         let clip_sdf         = self.clip_sdf.is_some();
         let copy_intensity   = self.copy.is_some();
+        let depth_enabled    = self.depth.is_some() && self.depth.as_ref().unwrap().is_enabled();
+        let depth_fog        = depth_enabled && self.depth_fog.is_some() && self.depth_fog.as_ref().unwrap().is_enabled();
+        let blur_bokeh_depth = depth_enabled && self.blur_bokeh_depth.is_some() && self.blur_bokeh_depth.as_ref().unwrap().is_enabled();
 
         let mut final_is_multi_render_steps = true;
 
+        // 深度纹理与源内容对应, 深度相关效果需最先处理
+        if depth_fog {
+            self.flags.push(EPostprocessRenderType::DepthFog);
+            if let Some(item) = self.renderer_depth_fog.as_mut() {
+                item.param = self.depth_fog.as_ref().unwrap().clone();
+                item.depth = self.depth.as_ref().unwrap().clone();
+            } else {
+                self.renderer_depth_fog = Some(DepthFogRenderer::new(self.depth_fog.as_ref().unwrap(), self.depth.as_ref().unwrap(), resources));
+            }
+            final_is_multi_render_steps = false;
+        }
+        if blur_bokeh_depth {
+            self.flags.push(EPostprocessRenderType::BlurBokehDepth);
+            if let Some(item) = self.renderer_blur_bokeh_depth.as_mut() {
+                item.param = self.blur_bokeh_depth.as_ref().unwrap().clone();
+                item.depth = self.depth.as_ref().unwrap().clone();
+            } else {
+                self.renderer_blur_bokeh_depth = Some(BlurBokehDepthRenderer::new(self.blur_bokeh_depth.as_ref().unwrap(), self.depth.as_ref().unwrap(), resources));
+            }
+            final_is_multi_render_steps = false;
+        }
         if color_effect {
             self.flags.push(EPostprocessRenderType::ColorEffect);
             if self.renderer_coloreffect.is_none() { self.renderer_coloreffect = Some(ColorEffectRenderer { hsb: None, balance: None, vignette: None, scale: None, filter: None, uniform: resources.uniform_buffer() } ) }
@@ -859,19 +1558,54 @@ impl PostProcess {
             final_is_multi_render_steps = false;
         }
         if horizon_glitch {
-            self.horizon_glitch.as_mut().unwrap().update(delta_time);
+            self.horizon_glitch.as_mut().unwrap().update(&self.time);
             if let Some(item) = self.renderer_horizon_glitch.as_mut() {
                 item.strength = self.horizon_glitch.as_ref().unwrap().strength;
                 item.fade = self.horizon_glitch.as_ref().unwrap().fade;
             } else {
                 self.renderer_horizon_glitch = Some(HorizonGlitchRenderer::new(self.horizon_glitch.as_ref().unwrap(), resources));
             }
-            self.horizon_glitch_instance = horizon_glitch_render_calc(self.horizon_glitch.as_ref().unwrap(), self.renderer_horizon_glitch.as_ref().unwrap(), device, queue, resources);
+            self.horizon_glitch_instance = match horizon_glitch_render_calc(self.horizon_glitch.as_ref().unwrap(), self.renderer_horizon_glitch.as_mut().unwrap(), device, queue, resources) {
+                Ok(instance) => instance,
+                Err(err) => {
+                    if self.error_policy == EPostprocessErrorPolicy::Abort {
+                        return Err(err);
+                    }
+                    log::warn!("PostProcess skip {:?}: {:?}", EPostprocessRenderType::HorizonGlitch, err);
+                    None
+                },
+            };
             if self.horizon_glitch_instance.is_some() {
                 self.flags.push(EPostprocessRenderType::HorizonGlitch);
                 final_is_multi_render_steps = true;
             }
         }
+        if chromatic_aberration {
+            self.flags.push(EPostprocessRenderType::ChromaticAberration);
+            if let Some(item) = self.renderer_chromatic_aberration.as_mut() {
+                item.param = self.chromatic_aberration.as_ref().unwrap().clone();
+            } else {
+                self.renderer_chromatic_aberration = Some(ChromaticAberrationRenderer::new(self.chromatic_aberration.as_ref().unwrap(), resources));
+            }
+            final_is_multi_render_steps = false;
+        }
+        if film_grain {
+            self.flags.push(EPostprocessRenderType::FilmGrain);
+            if self.renderer_film_grain.is_none() {
+                self.renderer_film_grain = Some(FilmGrainRenderer::new(self.film_grain.as_ref().unwrap(), resources));
+            }
+            self.renderer_film_grain.as_mut().unwrap().update(self.film_grain.as_ref().unwrap(), &self.time);
+            final_is_multi_render_steps = false;
+        }
+        if pixelate {
+            self.flags.push(EPostprocessRenderType::Pixelate);
+            if let Some(item) = self.renderer_pixelate.as_mut() {
+                item.param = self.pixelate.as_ref().unwrap().clone();
+            } else {
+                self.renderer_pixelate = Some(PixelateRenderer::new(self.pixelate.as_ref().unwrap(), resources));
+            }
+            final_is_multi_render_steps = false;
+        }
         if filter_sobel {
             self.flags.push(EPostprocessRenderType::FilterSobel);
             if let Some(item) = self.renderer_filter_sobel.as_mut() {
@@ -881,6 +1615,42 @@ impl PostProcess {
             }
             final_is_multi_render_steps = false;
         }
+        if drop_shadow {
+            self.flags.push(EPostprocessRenderType::DropShadow);
+            if let Some(item) = self.renderer_drop_shadow.as_mut() {
+                item.update(self.drop_shadow.as_ref().unwrap());
+            } else {
+                self.renderer_drop_shadow = Some(DropShadowRenderer::new(self.drop_shadow.as_ref().unwrap(), resources));
+            }
+            final_is_multi_render_steps = true;
+        }
+        if glow {
+            self.flags.push(EPostprocessRenderType::Glow);
+            if let Some(item) = self.renderer_glow.as_mut() {
+                item.update(self.glow.as_ref().unwrap());
+            } else {
+                self.renderer_glow = Some(GlowRenderer::new(self.glow.as_ref().unwrap(), resources));
+            }
+            final_is_multi_render_steps = true;
+        }
+        if outline {
+            self.flags.push(EPostprocessRenderType::Outline);
+            if let Some(item) = self.renderer_outline.as_mut() {
+                item.param = self.outline.as_ref().unwrap().clone();
+            } else {
+                self.renderer_outline = Some(OutlineRenderer::new(self.outline.as_ref().unwrap(), resources));
+            }
+            final_is_multi_render_steps = true;
+        }
+        if jump_flood {
+            self.flags.push(EPostprocessRenderType::JumpFlood);
+            if let Some(item) = self.renderer_jump_flood.as_mut() {
+                item.update(self.jump_flood.as_ref().unwrap(), resources);
+            } else {
+                self.renderer_jump_flood = Some(JumpFloodRenderer::new(self.jump_flood.as_ref().unwrap(), resources));
+            }
+            final_is_multi_render_steps = true;
+        }
         if image_mask {
             self.flags.push(EPostprocessRenderType::ImageMask);
             if let Some(item) = self.renderer_image_mask.as_mut() {
@@ -917,5 +1687,13 @@ impl PostProcess {
         if final_step_by_draw_final && final_is_multi_render_steps {
             self.flags.push(EPostprocessRenderType::FinalCopyIntensity);
         }
+
+        // 写入模板时, 最终绘制的 ClipSdf 丢弃形状外片元
+        let stencil_write = self.stencil.as_ref().map(|v| v.is_write()).unwrap_or(false);
+        if let Some(item) = self.renderer_clip_sdf.as_mut() {
+            item.cutoff = if stencil_write && self.flags.last() == Some(&EPostprocessRenderType::ClipSdf) { 0.5 } else { 0. };
+        }
+
+        Ok(())
     }
 }
\ No newline at end of file
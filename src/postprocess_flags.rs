@@ -13,6 +13,15 @@ pub struct PostprocessFlags {
     pub blur_gauss:         bool,
     pub image_mask:         bool,
     pub clip_sdf:           bool,
+    pub depth_fog:          bool,
+    pub blur_bokeh_depth:   bool,
+    pub drop_shadow:        bool,
+    pub glow:               bool,
+    pub outline:            bool,
+    pub jump_flood:         bool,
+    pub chromatic_aberration: bool,
+    pub film_grain:         bool,
+    pub pixelate:           bool,
     pub active_count:       u8,
 }
 
@@ -32,12 +41,21 @@ impl Default for PostprocessFlags {
             blur_gauss: false,
             image_mask: false,
             clip_sdf: false,
+            depth_fog: false,
+            blur_bokeh_depth: false,
+            drop_shadow: false,
+            glow: false,
+            outline: false,
+            jump_flood: false,
+            chromatic_aberration: false,
+            film_grain: false,
+            pixelate: false,
             active_count: 0
         }
     }
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum EPostprocessRenderType {
     ColorEffect,
     BlurDual,
@@ -53,4 +71,13 @@ pub enum EPostprocessRenderType {
     BlurGauss,
     ImageMask,
     ClipSdf,
+    DepthFog,
+    BlurBokehDepth,
+    DropShadow,
+    Glow,
+    Outline,
+    JumpFlood,
+    ChromaticAberration,
+    FilmGrain,
+    Pixelate,
 }
\ No newline at end of file
@@ -3,7 +3,13 @@ pub use super::postprocess::*;
 pub use super::effect::*;
 pub use super::material::{
     blend::*,
-    create_target
+    create_target,
+    tools::EShaderLanguage,
 };
 pub use super::temprory_render_target::*;
+pub use super::stencil::*;
+pub use super::time::*;
+pub use super::error::*;
+pub use super::postprocess_flags::EPostprocessRenderType;
 pub use super::image_effect::*;
+pub use super::renderer::jump_flood::jump_flood_render;
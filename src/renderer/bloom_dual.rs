@@ -4,7 +4,7 @@ use pi_render::{rhi::{device::RenderDevice, asset::RenderRes, pipeline::RenderPi
 use pi_share::Share;
 
 
-use crate::{effect::*, temprory_render_target::PostprocessTexture, image_effect::*, IDENTITY_MATRIX, SimpleRenderExtendsData, material::{create_default_target, create_target, blend::{get_blend_state, EBlend}, FORMAT},};
+use crate::{effect::*, temprory_render_target::PostprocessTexture, image_effect::*, IDENTITY_MATRIX, SimpleRenderExtendsData, error::EPostprocessError, material::{create_default_target, create_target, blend::{get_blend_state, EBlend}, FORMAT},};
 
 // const ERROR_NOT_GET_FILTER_BRIGHNESS_USED_RT_ID: &str = "NOT_GET_FILTER_BRIGHNESS_USED_RT_ID";
 // const ERROR_NOT_GET_RT_BY_FILTER_BRIGHNESS_USED_ID: &str = "NOT_GET_RT_BY_FILTER_BRIGHNESS_USED_ID";
@@ -26,7 +26,7 @@ pub fn bloom_dual_render(
     target_format: wgpu::TextureFormat,
     src_premultiplied: bool,
     dst_premultiply: bool,
-) -> PostprocessTexture {
+) -> Result<PostprocessTexture, EPostprocessError> {
 
     let color_state: wgpu::ColorTargetState = create_default_target(target_format);
     let color_state_for_add: wgpu::ColorTargetState = create_target(FORMAT, get_blend_state(EBlend::Add), wgpu::ColorWrites::ALL);
@@ -36,7 +36,7 @@ pub fn bloom_dual_render(
     let mut to_w = from_w;
     let mut to_h = from_h;
 
-    let filterresult = EffectBlurDual::get_target(None, &source, (to_w, to_h), safeatlas, target_type, target_format, false); 
+    let filterresult = EffectBlurDual::get_target(None, &source, (to_w, to_h), safeatlas, target_type, target_format); 
     let draw = EffectFilterBrightness::ready(
         &bloom_dual.brightness_filter, resources, renderdevice, queue, 0,
         (to_w, to_h), &IDENTITY_MATRIX,
@@ -44,7 +44,7 @@ pub fn bloom_dual_render(
         safeatlas, target_type, pipelines,
         color_state.clone(), None, false,
         src_premultiplied, false
-    ).unwrap();
+    ).ok_or(EPostprocessError::MissingEffectResource(EffectFilterBrightness::KEY))?;
     // log::warn!("Filter: {:?}", (source.get_rect(),  filterresult.get_rect()));
     let draw = PostProcessDraw::Temp(filterresult.get_rect(), draw, filterresult.view.clone() );
     // draw.draw(Some(encoder), None);
@@ -60,7 +60,7 @@ pub fn bloom_dual_render(
             to_h = to_h / 2;
             realiter += 1;
     
-            let result = EffectBlurDual::get_target(None, &tempsource, (to_w, to_h), safeatlas, target_type, target_format, false); 
+            let result = EffectBlurDual::get_target(None, &tempsource, (to_w, to_h), safeatlas, target_type, target_format); 
             // log::warn!("Down: {:?}", (tempsource.get_rect(),  result.get_rect()));
             let draw = EffectBlurDual::ready(
                 bloom_dual.blur_duals.downs.get(idx).unwrap(), resources,
@@ -73,7 +73,7 @@ pub fn bloom_dual_render(
                 color_state.clone(),
                 None,
                 false, false
-            ).unwrap();
+            ).ok_or(EPostprocessError::MissingEffectResource(EffectBlurDual::KEY))?;
 
             tempsource = result.clone();
 
@@ -95,7 +95,7 @@ pub fn bloom_dual_render(
 
             temptarget = temptargets.pop();
             
-            let result = EffectBlurDual::get_target(temptarget, &tempsource, (to_w, to_h), safeatlas, target_type, target_format, false); 
+            let result = EffectBlurDual::get_target(temptarget, &tempsource, (to_w, to_h), safeatlas, target_type, target_format); 
             // log::warn!("Up: {:?}", (tempsource.get_rect(),  result.get_rect()));
             let draw = EffectBlurDual::ready(
                 bloom_dual.blur_duals.ups.get(idx).unwrap(), resources,
@@ -108,7 +108,7 @@ pub fn bloom_dual_render(
                 color_state_for_add.clone(),
                 None,
                 false, false
-            ).unwrap();
+            ).ok_or(EPostprocessError::MissingEffectResource(EffectBlurDual::KEY))?;
 
             let draw = PostProcessDraw::Temp(result.get_rect(), draw, result.view.clone() );
             tempsource = result;
@@ -118,14 +118,14 @@ pub fn bloom_dual_render(
     }
 
     if realiter == 0 {
-        return source;
+        return Ok(source);
     } else {
         match &source.view {
             pi_render::renderer::texture::ETextureViewUsage::SRT(_) => {
                 // let mut copyparam = CopyIntensity::default();
                 // copyparam.intensity = bloom_dual.intensity;
                 let dst_size = (source.use_w(), source.use_h());
-                let result = EffectCopy::get_target(Some(source), &tempsource, dst_size, safeatlas, target_type, target_format, false);
+                let result = EffectCopy::get_target(Some(source), &tempsource, dst_size, safeatlas, target_type, target_format);
                 let draw = EffectCopy::ready(
                     &bloom_dual.copy_intensity, resources,
                     renderdevice, queue, 0, dst_size,
@@ -135,15 +135,15 @@ pub fn bloom_dual_render(
                     safeatlas, target_type, pipelines,
                     color_state_for_add.clone(), depth_stencil, false,
                     false, false
-                ).unwrap();
+                ).ok_or(EPostprocessError::MissingEffectResource(EffectCopy::KEY))?;
                 // log::warn!("End: {:?}", (tempsource.get_rect(),  result.get_rect()));
                 let draw = PostProcessDraw::Temp(result.get_rect(), draw, result.view.clone() );
                 draws.push(draw);
-                return result;
+                return Ok(result);
             },
             _ => {
                 let dst_size = (source.use_w(), source.use_h());
-                let result = EffectCopy::get_target(None, &tempsource, dst_size, safeatlas, target_type, target_format, false);
+                let result = EffectCopy::get_target(None, &tempsource, dst_size, safeatlas, target_type, target_format);
                 let draw = EffectCopy::ready(
                     &bloom_dual.copy, resources,
                     renderdevice, queue, 0, dst_size,
@@ -153,7 +153,7 @@ pub fn bloom_dual_render(
                     safeatlas, target_type, pipelines,
                     color_state.clone(), None, false,
                     false, false
-                ).unwrap();
+                ).ok_or(EPostprocessError::MissingEffectResource(EffectCopy::KEY))?;
                 // log::warn!("End 0: {:?}", (source.get_rect(),  result.get_rect()));
                 let draw = PostProcessDraw::Temp(result.get_rect(), draw, result.view.clone() );
                 // draw.draw(Some(encoder), None);
@@ -168,13 +168,13 @@ pub fn bloom_dual_render(
                     safeatlas, target_type, pipelines,
                     color_state_for_add.clone(), depth_stencil, true,
                     false, dst_premultiply
-                ).unwrap();
+                ).ok_or(EPostprocessError::MissingEffectResource(EffectCopy::KEY))?;
                 // log::warn!("End 1: {:?}", (tempsource.get_rect(),  result.get_rect()));
                 let draw = PostProcessDraw::Temp(result.get_rect(), draw, result.view.clone() );
                 // draws.push(draw);
                 draws.push(draw);
 
-                return result;
+                return Ok(result);
             },
         };
 
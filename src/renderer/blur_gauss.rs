@@ -10,7 +10,7 @@ use pi_render::{
 };
 use pi_share::Share;
 
-use crate::{effect::*, temprory_render_target::PostprocessTexture, image_effect::*, IDENTITY_MATRIX};
+use crate::{effect::*, temprory_render_target::PostprocessTexture, image_effect::*, error::EPostprocessError, IDENTITY_MATRIX};
 
 
 pub fn blur_gauss_render(
@@ -31,7 +31,7 @@ pub fn blur_gauss_render(
     target_format: wgpu::TextureFormat,
     src_premultiplied: bool,
     dst_premultiply: bool,
-) -> PostprocessTexture {
+) -> Result<PostprocessTexture, EPostprocessError> {
     let dst_size = (source.use_w(), source.use_h());
     // let mut drawparam = BlurGaussRenderer { param: param.clone(), ishorizon: true, texwidth: source.width(), texheight: source.height() };
     let draw = EffectBlurGauss::ready(
@@ -42,8 +42,8 @@ pub fn blur_gauss_render(
         safeatlas, target_type, pipelines,
         color_state.clone(), None,
         src_premultiplied, false
-    ).unwrap();
-    let result = EffectBlurDual::get_target(None, &source, dst_size, safeatlas, target_type, target_format, true); 
+    ).ok_or(EPostprocessError::MissingEffectResource(EffectBlurGauss::KEY))?;
+    let result = EffectBlurDual::get_target(None, &source, dst_size, safeatlas, target_type, target_format); 
     let draw = PostProcessDraw::Temp(result.get_rect(), draw, result.view.clone() );
     draws.push(draw);
     
@@ -56,10 +56,10 @@ pub fn blur_gauss_render(
         safeatlas, target_type, pipelines,
         color_state.clone(), None,
         false, dst_premultiply
-    ).unwrap();
-    let result = EffectBlurDual::get_target(target, &result, dst_size, safeatlas, target_type, target_format, true); 
+    ).ok_or(EPostprocessError::MissingEffectResource(EffectBlurGauss::KEY))?;
+    let result = EffectBlurDual::get_target(target, &result, dst_size, safeatlas, target_type, target_format); 
     let draw = PostProcessDraw::Temp(result.get_rect(), draw, result.view.clone() );
     draws.push(draw);
 
-    result
+    Ok(result)
 }
\ No newline at end of file
@@ -0,0 +1,60 @@
+use pi_assets::mgr::AssetMgr;
+use pi_render::{
+    rhi::{
+        device::RenderDevice, asset::RenderRes, pipeline::RenderPipeline, RenderQueue
+    },
+    components::view::target_alloc::{SafeAtlasAllocator, TargetType},
+    renderer::pipeline::DepthStencilState
+};
+use pi_share::Share;
+
+use crate::{effect::*, temprory_render_target::PostprocessTexture, image_effect::*, error::EPostprocessError, IDENTITY_MATRIX};
+
+use super::blur_gauss::blur_gauss_render;
+
+
+pub fn drop_shadow_render(
+    param: &DropShadowRenderer,
+    renderdevice: &RenderDevice,
+    queue: & RenderQueue,
+    matrix: &[f32],
+    safeatlas: &SafeAtlasAllocator,
+    source: &PostprocessTexture,
+    target: Option<PostprocessTexture>,
+    draws: &mut Vec<PostProcessDraw>,
+    resources: &SingleImageEffectResource,
+    pipelines: &Share<AssetMgr<RenderRes<RenderPipeline>>>,
+    color_state: wgpu::ColorTargetState,
+    depth_stencil: Option<DepthStencilState>,
+    target_type: TargetType,
+    target_format: wgpu::TextureFormat,
+    src_premultiplied: bool,
+    dst_premultiply: bool,
+) -> Result<PostprocessTexture, EPostprocessError> {
+    // 只使用 alpha, 模糊结果不预乘
+    let shadow = if param.param.blur().is_enabled() {
+        blur_gauss_render(
+            &param.blur.0, &param.blur.1,
+            renderdevice, queue, matrix,
+            safeatlas, source, None, draws, resources, pipelines, color_state.clone(), depth_stencil, target_type, target_format, src_premultiplied, false
+        )?
+    } else {
+        source.clone()
+    };
+
+    let dst_size = (source.use_w(), source.use_h());
+    let draw = EffectDropShadow::ready(
+        param, &shadow,
+        resources, renderdevice, queue, 0,
+        dst_size, &IDENTITY_MATRIX,
+        1., 0., source,
+        safeatlas, target_type, pipelines,
+        color_state, None,
+        src_premultiplied, dst_premultiply
+    ).ok_or(EPostprocessError::MissingEffectResource(EffectDropShadow::KEY))?;
+    let result = EffectDropShadow::get_target_excluding(target, &[source, &shadow], dst_size, safeatlas, target_type, target_format);
+    let draw = PostProcessDraw::Temp(result.get_rect(), draw, result.view.clone() );
+    draws.push(draw);
+
+    Ok(result)
+}
@@ -11,17 +11,17 @@ use pi_render::{
 };
 use pi_share::Share;
 
-use crate::{effect::*, temprory_render_target::PostprocessTexture, image_effect::*, IDENTITY_MATRIX};
+use crate::{effect::*, temprory_render_target::PostprocessTexture, image_effect::*, error::EPostprocessError, IDENTITY_MATRIX};
 
-const MAX_INSTANCE_COUNT: usize = SingleImageEffectResource::INSTANCE_RANGE_SIZE / (4 * 4);
+const MAX_INSTANCE_COUNT: usize = ImageEffectInstancePool::BLOCK_SIZE / (4 * 4);
 
 pub fn horizon_glitch_render_calc(
     base: &HorizonGlitch,
-    param: &HorizonGlitchRenderer,
+    param: &mut HorizonGlitchRenderer,
     _renderdevice: &RenderDevice,
     queue: & RenderQueue,
     resources: &SingleImageEffectResource,
-) -> Option<RenderVertices> {
+) -> Result<Option<RenderVertices>, EPostprocessError> {
     let items = base.get_items();
     let count = items.len();
 
@@ -61,23 +61,25 @@ pub fn horizon_glitch_render_calc(
     }
 
     if instance_count > 0 {
-        if let Some(offset) = &param.instance {
-            let data = bytemuck::cast_slice(&instance_data);
-            queue.write_buffer(resources.instancebuffer.buffer(), offset.0 as u64, data);
-            let buffer = EVerticesBufferUsage::EVBRange(resources.instancebuffer.clone());
-            let instance = RenderVertices {
-                slot: 1,
-                buffer,
-                buffer_range: Some(Range { start: offset.0 as u64, end: data.len() as u64 }),
-                size_per_value: 16,
-            };
-    
-            Some(instance)
-        } else {
-            None
+        let data: &[u8] = bytemuck::cast_slice(&instance_data);
+        // 区间不足时重新分配, 原区间归还缓冲池
+        if param.instance.as_ref().map(|v| v.size() < data.len()).unwrap_or(true) {
+            param.instance = None;
+            param.instance = Some(resources.instance_range(data.len())?);
         }
+        let offset = param.instance.as_ref().unwrap();
+        queue.write_buffer(offset.buffer.buffer(), offset.offset as u64, data);
+        let buffer = EVerticesBufferUsage::EVBRange(offset.buffer.clone());
+        let instance = RenderVertices {
+            slot: 1,
+            buffer,
+            buffer_range: Some(Range { start: offset.offset as u64, end: (offset.offset + data.len()) as u64 }),
+            size_per_value: 16,
+        };
+
+        Ok(Some(instance))
     } else {
-        None
+        Ok(None)
     }
 }
  
@@ -99,7 +101,7 @@ pub fn horizon_glitch_render(
     target_format: wgpu::TextureFormat,
     src_premultiplied: bool,
     dst_premultiply: bool,
-) -> PostprocessTexture {
+) -> Result<PostprocessTexture, EPostprocessError> {
     let dst_size = (source.use_w(), source.use_h());
     let draw = EffectCopy::ready(
         &param.copy, resources, renderdevice, queue, 0,
@@ -108,8 +110,8 @@ pub fn horizon_glitch_render(
         safeatlas, target_type, pipelines,
         color_state.clone(), None, false,
         src_premultiplied, dst_premultiply
-    ).unwrap();
-    let result = EffectBlurDual::get_target(target, &source, dst_size, safeatlas, target_type, target_format, true); 
+    ).ok_or(EPostprocessError::MissingEffectResource(EffectCopy::KEY))?;
+    let result = EffectBlurDual::get_target(target, &source, dst_size, safeatlas, target_type, target_format); 
     let draw = PostProcessDraw::Temp(result.get_rect(), draw, result.view.clone() );
     draws.push(draw);
 
@@ -122,12 +124,12 @@ pub fn horizon_glitch_render(
             safeatlas, target_type, pipelines,
             color_state.clone(), None,
             src_premultiplied, dst_premultiply
-        ).unwrap();
+        ).ok_or(EPostprocessError::MissingEffectResource(EffectHorizonGlitch::KEY))?;
         let draw = PostProcessDraw::Temp(result.get_rect(), draw, result.view.clone() );
         draws.push(draw);
-        result
+        Ok(result)
     } else {
-        result
+        Ok(result)
     }
 
 }
\ No newline at end of file
@@ -0,0 +1,76 @@
+use pi_assets::mgr::AssetMgr;
+use pi_render::{
+    rhi::{
+        device::RenderDevice, asset::RenderRes, pipeline::RenderPipeline, RenderQueue
+    },
+    components::view::target_alloc::{SafeAtlasAllocator, TargetType},
+    renderer::pipeline::DepthStencilState
+};
+use pi_share::Share;
+
+use crate::{effect::*, temprory_render_target::PostprocessTexture, image_effect::*, material::create_default_target, error::EPostprocessError, IDENTITY_MATRIX};
+
+
+/// 由源内容 alpha 生成有向距离场 - 编码见 JumpFloodSdf
+/// * 其他效果需要距离场时调用, 返回的纹理与 source 使用区域尺寸一致
+/// * 绘制步骤追加到 draws, 需在读取结果前提交
+/// * `target`
+///   * 输出目标, None 时分配避开 source 与中间结果的临时目标; 指定时不能与 source 同纹理
+pub fn jump_flood_render(
+    param: &JumpFloodRenderer,
+    renderdevice: &RenderDevice,
+    queue: & RenderQueue,
+    _: &[f32],
+    safeatlas: &SafeAtlasAllocator,
+    source: &PostprocessTexture,
+    target: Option<PostprocessTexture>,
+    draws: &mut Vec<PostProcessDraw>,
+    resources: &SingleImageEffectResource,
+    pipelines: &Share<AssetMgr<RenderRes<RenderPipeline>>>,
+    color_state: wgpu::ColorTargetState,
+    _: Option<DepthStencilState>,
+    target_type: TargetType,
+    target_format: wgpu::TextureFormat,
+    src_premultiplied: bool,
+    dst_premultiply: bool,
+) -> Result<PostprocessTexture, EPostprocessError> {
+    let dst_size = (source.use_w(), source.use_h());
+    let jumps = param.param.jump_count(dst_size);
+
+    // 中间结果为坐标编码, 不可混合
+    let mut seeds = source.clone();
+    let mut steps = vec![(EJumpFloodPass::Seed, 0)];
+    for i in (0..jumps).rev() {
+        steps.push((EJumpFloodPass::Jump, 1 << i));
+    }
+    for (index, (pass, jump)) in steps.into_iter().enumerate() {
+        let draw = EffectJumpFlood::ready(
+            param, index, pass, jump, source,
+            resources, renderdevice, queue, 0,
+            dst_size, &IDENTITY_MATRIX,
+            1., 0., &seeds,
+            safeatlas, target_type, pipelines,
+            create_default_target(target_format), None,
+            false, false
+        ).ok_or(EPostprocessError::MissingEffectResource(EffectJumpFlood::KEY))?;
+        let result = EffectJumpFlood::get_target_excluding(None, &[&seeds, source], dst_size, safeatlas, target_type, target_format);
+        let draw = PostProcessDraw::Temp(result.get_rect(), draw, result.view.clone() );
+        draws.push(draw);
+        seeds = result;
+    }
+
+    let draw = EffectJumpFlood::ready(
+        param, jumps as usize + 1, EJumpFloodPass::Resolve, 0, source,
+        resources, renderdevice, queue, 0,
+        dst_size, &IDENTITY_MATRIX,
+        1., 0., &seeds,
+        safeatlas, target_type, pipelines,
+        color_state, None,
+        src_premultiplied, dst_premultiply
+    ).ok_or(EPostprocessError::MissingEffectResource(EffectJumpFlood::KEY))?;
+    let result = EffectJumpFlood::get_target_excluding(target, &[&seeds, source], dst_size, safeatlas, target_type, target_format);
+    let draw = PostProcessDraw::Temp(result.get_rect(), draw, result.view.clone() );
+    draws.push(draw);
+
+    Ok(result)
+}
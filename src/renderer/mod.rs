@@ -11,4 +11,8 @@ pub mod bloom_dual;
 // pub mod radial_wave;
 pub mod horizon_glitch;
 pub mod blur_gauss;
+pub mod drop_shadow;
+pub mod glow;
+pub mod outline;
+pub mod jump_flood;
 // pub mod renderer;
@@ -0,0 +1,67 @@
+use pi_assets::mgr::AssetMgr;
+use pi_render::{
+    rhi::{
+        device::RenderDevice, asset::RenderRes, pipeline::RenderPipeline, RenderQueue
+    },
+    components::view::target_alloc::{SafeAtlasAllocator, TargetType},
+    renderer::pipeline::DepthStencilState
+};
+use pi_share::Share;
+
+use crate::{effect::*, temprory_render_target::PostprocessTexture, image_effect::*, error::EPostprocessError, IDENTITY_MATRIX};
+
+
+pub fn outline_render(
+    param: &OutlineRenderer,
+    renderdevice: &RenderDevice,
+    queue: & RenderQueue,
+    _: &[f32],
+    safeatlas: &SafeAtlasAllocator,
+    source: &PostprocessTexture,
+    target: Option<PostprocessTexture>,
+    draws: &mut Vec<PostProcessDraw>,
+    resources: &SingleImageEffectResource,
+    pipelines: &Share<AssetMgr<RenderRes<RenderPipeline>>>,
+    color_state: wgpu::ColorTargetState,
+    _: Option<DepthStencilState>,
+    target_type: TargetType,
+    target_format: wgpu::TextureFormat,
+    src_premultiplied: bool,
+    dst_premultiply: bool,
+) -> Result<PostprocessTexture, EPostprocessError> {
+    let dst_size = (source.use_w(), source.use_h());
+
+    // 距离场 - 水平, 垂直两步, 结果不透明
+    let mut distance = source.clone();
+    for pass in [EOutlinePass::Horizontal, EOutlinePass::Vertical] {
+        let draw = EffectOutline::ready(
+            param, pass, &distance,
+            resources, renderdevice, queue, 0,
+            dst_size, &IDENTITY_MATRIX,
+            1., 0., &distance,
+            safeatlas, target_type, pipelines,
+            color_state.clone(), None,
+            false, false
+        ).ok_or(EPostprocessError::MissingEffectResource(EffectOutline::KEY))?;
+        // source 在 Compose 中仍需采样, 距离场同时避开
+        let result = EffectOutline::get_target_excluding(None, &[&distance, source], dst_size, safeatlas, target_type, target_format);
+        let draw = PostProcessDraw::Temp(result.get_rect(), draw, result.view.clone() );
+        draws.push(draw);
+        distance = result;
+    }
+
+    let draw = EffectOutline::ready(
+        param, EOutlinePass::Compose, &distance,
+        resources, renderdevice, queue, 0,
+        dst_size, &IDENTITY_MATRIX,
+        1., 0., source,
+        safeatlas, target_type, pipelines,
+        color_state, None,
+        src_premultiplied, dst_premultiply
+    ).ok_or(EPostprocessError::MissingEffectResource(EffectOutline::KEY))?;
+    let result = EffectOutline::get_target_excluding(target, &[source, &distance], dst_size, safeatlas, target_type, target_format);
+    let draw = PostProcessDraw::Temp(result.get_rect(), draw, result.view.clone() );
+    draws.push(draw);
+
+    Ok(result)
+}
@@ -0,0 +1,90 @@
+struct Model {
+    vertexMatrix: mat4x4<f32>,
+    diffuseMat: vec4<f32>,
+
+    depthMat: vec4<f32>,
+
+    offset: f32,
+    iteration: f32,
+    near: f32,
+    far: f32,
+
+    focus: f32,
+    range: f32,
+    reversed: f32,
+    depth: f32,
+
+    alpha: f32,
+    src_preimultiplied: f32,
+    dst_preimultiply: f32,
+    _wasm_0: f32,
+};
+
+@group(0) @binding(0) var<uniform> param: Model;
+@group(0) @binding(1) var diffuseTex: texture_2d<f32>;
+@group(0) @binding(2) var sampler_diffuseTex: sampler;
+@group(0) @binding(3) var depthTex: texture_2d<f32>;
+@group(0) @binding(4) var sampler_depthTex: sampler;
+
+fn texColor(src: vec4<f32>) -> vec4<f32> {
+    return vec4<f32>(src.rgb / mix(1.0, src.a, step(0.5, param.src_preimultiplied)), src.a);
+}
+
+const GLODEN_COS: f32 = -0.7373688782616119;
+const GLODEN_SIN: f32 = 0.675490294061441;
+const GLODEN_ROT: mat2x2<f32> = mat2x2<f32>(GLODEN_COS, GLODEN_SIN, -GLODEN_SIN, GLODEN_COS);
+
+fn clampUV(uv: vec2<f32>, minUV: vec2<f32>, maxUV: vec2<f32>) -> vec2<f32> {
+    return clamp(uv, minUV, maxUV);
+}
+
+// 深度缓冲值 [0, 1] 转换为相机空间距离
+fn linearDepth(uv: vec2<f32>) -> f32 {
+    var d = textureSampleLevel(depthTex, sampler_depthTex, uv, 0.0).r;
+    d = mix(d, 1.0 - d, step(0.5, param.reversed));
+    return param.near * param.far / (param.far - d * (param.far - param.near));
+}
+
+// 在非一致控制流中调用, 只能使用显式 LOD 采样
+fn BokehBlur(uv: vec2<f32>, blurRadius: f32) -> vec4<f32> {
+    var accumulator = vec4<f32>(0.0);
+    var divisor = vec4<f32>(0.0);
+
+    var r = 1.0;
+    var angle = vec2<f32>(0.0, blurRadius);
+
+    for (var j = 0; j < 32; j++) {
+        if (f32(j) == param.iteration) {
+            break;
+        }
+        r += 1.0 / r;
+        angle = GLODEN_ROT * angle;
+
+        let tempuv = uv + (r - 1.0) * angle;
+        let bokeh = texColor(textureSampleLevel(diffuseTex, sampler_diffuseTex, clampUV(tempuv, param.diffuseMat.xy, param.diffuseMat.zw + param.diffuseMat.xy), 0.0));
+
+        accumulator += bokeh * bokeh;
+        divisor += bokeh;
+    }
+
+    return accumulator / divisor;
+}
+
+@fragment
+fn main(@location(0) postiion_cs: vec2<f32>) -> @location(0) vec4<f32> {
+    let vMainUV = postiion_cs * param.diffuseMat.zw + param.diffuseMat.xy;
+    let vDepthUV = postiion_cs * param.depthMat.zw + param.depthMat.xy;
+
+    // 弥散圈 - 与焦点距离超过 range 时达到最大模糊半径
+    let z = linearDepth(vDepthUV);
+    let coc = clamp(abs(z - param.focus) / max(param.range, 0.0001), 0.0, 1.0);
+
+    var c: vec4<f32>;
+    if (0.001 < coc) {
+        c = BokehBlur(vMainUV, param.offset * coc);
+    } else {
+        c = texColor(textureSampleLevel(diffuseTex, sampler_diffuseTex, vMainUV, 0.0));
+    }
+    c.a *= param.alpha;
+    return vec4<f32>(c.rgb * mix(1.0, c.a, step(0.5, param.dst_preimultiply)), c.a);
+}
@@ -0,0 +1,40 @@
+struct Model {
+    vertexMatrix: mat4x4<f32>,
+    diffuseMat: vec4<f32>,
+
+    depthMat: vec4<f32>,
+
+    offset: f32,
+    iteration: f32,
+    near: f32,
+    far: f32,
+
+    focus: f32,
+    range: f32,
+    reversed: f32,
+    depth: f32,
+
+    alpha: f32,
+    src_preimultiplied: f32,
+    dst_preimultiply: f32,
+    _wasm_0: f32,
+};
+
+@group(0) @binding(0) var<uniform> param: Model;
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) postiion_cs: vec2<f32>,
+};
+
+@vertex
+fn main(@location(0) position: vec2<f32>) -> VertexOutput {
+    var out: VertexOutput;
+
+    out.position = param.vertexMatrix * vec4<f32>(position * 2.0, 1.0, 1.0);
+    out.position.z = param.depth;
+
+    out.postiion_cs = position + 0.5;
+    out.postiion_cs.y = 1.0 - out.postiion_cs.y;
+    return out;
+}
@@ -0,0 +1,69 @@
+struct Model {
+    vertexMatrix: mat4x4<f32>,
+    diffuseMat: vec4<f32>,
+
+    offset: vec2<f32>,
+    intensity: f32,
+    dualmode: f32,
+
+    depth: f32,
+    alpha: f32,
+    src_preimultiplied: f32,
+    dst_preimultiply: f32,
+};
+
+@group(0) @binding(0) var<uniform> param: Model;
+@group(0) @binding(1) var diffuseTex: texture_2d<f32>;
+@group(0) @binding(2) var sampler_diffuseTex: sampler;
+
+fn texColor(src: vec4<f32>) -> vec4<f32> {
+    return vec4<f32>(src.rgb / mix(1.0, src.a, step(0.5, param.src_preimultiplied)), src.a);
+}
+
+struct FragmentInput {
+    @location(0) uv01: vec4<f32>,
+    @location(1) uv23: vec4<f32>,
+    @location(2) uv45: vec4<f32>,
+    @location(3) uv67: vec4<f32>,
+    @location(5) postiion_cs: vec2<f32>,
+};
+
+fn sample(uv: vec2<f32>) -> vec4<f32> {
+    return texColor(textureSample(diffuseTex, sampler_diffuseTex, uv));
+}
+
+fn down(uv: vec2<f32>, in: FragmentInput) -> vec4<f32> {
+    let color = sample(uv + in.uv01.xy)
+              + sample(uv + in.uv01.zw)
+              + sample(uv + in.uv23.xy)
+              + sample(uv + in.uv23.zw);
+
+    return color * 0.25;
+}
+
+fn up(uv: vec2<f32>, in: FragmentInput) -> vec4<f32> {
+    let color = sample(uv + in.uv01.xy) * 2.0
+              + sample(uv + in.uv01.zw) * 2.0
+              + sample(uv + in.uv23.xy) * 2.0
+              + sample(uv + in.uv23.zw) * 2.0
+              + sample(uv + in.uv45.xy)
+              + sample(uv + in.uv45.zw)
+              + sample(uv + in.uv67.xy)
+              + sample(uv + in.uv67.zw);
+    return color * 0.0833333; // 1/12
+}
+
+@fragment
+fn main(in: FragmentInput) -> @location(0) vec4<f32> {
+    let vMainUV = in.postiion_cs * param.diffuseMat.zw + param.diffuseMat.xy;
+
+    var c: vec4<f32>;
+    if (param.dualmode < 0.5) {
+        c = down(vMainUV, in);
+    } else {
+        c = up(vMainUV, in);
+    }
+
+    c.a *= param.alpha;
+    return vec4<f32>(c.rgb * mix(1.0, c.a, step(0.5, param.dst_preimultiply)) * param.intensity, c.a);
+}
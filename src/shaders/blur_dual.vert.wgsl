@@ -0,0 +1,43 @@
+struct Model {
+    vertexMatrix: mat4x4<f32>,
+    diffuseMat: vec4<f32>,
+
+    offset: vec2<f32>,
+    intensity: f32,
+    dualmode: f32,
+
+    depth: f32,
+    alpha: f32,
+    src_preimultiplied: f32,
+    dst_preimultiply: f32,
+};
+
+@group(0) @binding(0) var<uniform> param: Model;
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv01: vec4<f32>,
+    @location(1) uv23: vec4<f32>,
+    @location(2) uv45: vec4<f32>,
+    @location(3) uv67: vec4<f32>,
+    @location(5) postiion_cs: vec2<f32>,
+};
+
+@vertex
+fn main(@location(0) position: vec2<f32>) -> VertexOutput {
+    var out: VertexOutput;
+
+    out.position = param.vertexMatrix * vec4<f32>(position * 2.0, 1.0, 1.0);
+    out.position.z = param.depth;
+
+    out.postiion_cs = position + 0.5;
+    out.postiion_cs.y = 1.0 - out.postiion_cs.y;
+
+    let diff = param.offset * param.diffuseMat.zw;
+
+    out.uv01 = vec4<f32>(vec2<f32>( diff.x,  diff.y), vec2<f32>(-diff.x,  diff.y));
+    out.uv23 = vec4<f32>(vec2<f32>( diff.x, -diff.y), vec2<f32>(-diff.x, -diff.y));
+    out.uv45 = vec4<f32>(vec2<f32>( diff.x * 2.0, 0.0), vec2<f32>(-diff.x * 2.0, 0.0));
+    out.uv67 = vec4<f32>(vec2<f32>( 0.0, diff.y * 2.0), vec2<f32>(0.0, diff.y * 2.0));
+    return out;
+}
@@ -0,0 +1,63 @@
+struct Model {
+    vertexMatrix: mat4x4<f32>,
+    diffuseMat: vec4<f32>,
+
+    textureSize: vec2<f32>,
+    blurRadius: f32,
+    horizontal: f32,
+
+    depth: f32,
+    alpha: f32,
+    src_preimultiplied: f32,
+    dst_preimultiply: f32,
+};
+
+@group(0) @binding(0) var<uniform> param: Model;
+@group(0) @binding(1) var diffuseTex: texture_2d<f32>;
+@group(0) @binding(2) var sampler_diffuseTex: sampler;
+
+fn texColor(src: vec4<f32>) -> vec4<f32> {
+    return vec4<f32>(src.rgb / mix(1.0, src.a, step(0.5, param.src_preimultiplied)), src.a);
+}
+
+struct FragmentInput {
+    @location(0) support: f32,
+    @location(1) vUv: vec2<f32>,
+    @location(2) vOffsetScale: vec2<f32>,
+    @location(3) vGaussCoefficients: vec2<f32>,
+    @location(4) uvRect: vec4<f32>,
+};
+
+@fragment
+fn main(in: FragmentInput) -> @location(0) vec4<f32> {
+    let original_color = texColor(textureSampleLevel(diffuseTex, sampler_diffuseTex, in.vUv, 0.0));
+
+    // Incremental Gaussian Coefficent Calculation (See GPU Gems 3 pp. 877 - 889)
+    var gauss_coefficient = vec3<f32>(in.vGaussCoefficients, in.vGaussCoefficients.y * in.vGaussCoefficients.y);
+    // 当前采样点的权重
+    var avg_color = original_color * gauss_coefficient.x;
+
+    // 其他权重的点的采样（左右需要对称）, support 来自插值, 只能使用显式 LOD 采样
+    for (var i = 1.0; i <= 300.0; i += 2.0) {
+        if (i > in.support) {
+            break;
+        }
+        gauss_coefficient = vec3<f32>(gauss_coefficient.xy * gauss_coefficient.yz, gauss_coefficient.z);
+        var gauss_coefficient_subtotal = gauss_coefficient.x;
+        gauss_coefficient = vec3<f32>(gauss_coefficient.xy * gauss_coefficient.yz, gauss_coefficient.z);
+        gauss_coefficient_subtotal += gauss_coefficient.x;
+
+        let gauss_ratio = gauss_coefficient.x / gauss_coefficient_subtotal;
+        let offset = in.vOffsetScale * (i + gauss_ratio);
+
+        // 计算负方向和正方向上偏移的像素的像素值
+        let st0 = max(in.vUv - offset, in.uvRect.xy);
+        let st1 = min(in.vUv + offset, in.uvRect.zw);
+        avg_color += (texColor(textureSampleLevel(diffuseTex, sampler_diffuseTex, st0, 0.0)) + texColor(textureSampleLevel(diffuseTex, sampler_diffuseTex, st1, 0.0))) * gauss_coefficient_subtotal;
+    }
+
+    // 输出颜色值
+    var c = avg_color;
+    c.a *= param.alpha;
+    return vec4<f32>(c.rgb * mix(1.0, c.a, step(0.5, param.dst_preimultiply)), c.a);
+}
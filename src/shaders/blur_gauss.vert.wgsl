@@ -0,0 +1,85 @@
+struct Model {
+    vertexMatrix: mat4x4<f32>,
+    diffuseMat: vec4<f32>,
+
+    textureSize: vec2<f32>,
+    blurRadius: f32,
+    horizontal: f32,
+
+    depth: f32,
+    alpha: f32,
+    src_preimultiplied: f32,
+    dst_preimultiply: f32,
+};
+
+@group(0) @binding(0) var<uniform> param: Model;
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    // 采样像素个数（大致为模糊半径的两倍，因为需要左右对称）
+    @location(0) support: f32,
+    // 当前点得uv坐标
+    @location(1) vUv: vec2<f32>,
+    // 单个像素的uv偏移
+    @location(2) vOffsetScale: vec2<f32>,
+    // 高斯模糊系数（由顶点着色器根据模糊半径算出）
+    @location(3) vGaussCoefficients: vec2<f32>,
+    @location(4) uvRect: vec4<f32>,
+};
+
+// 根据半径计算高斯函数系数, 详见 blur_gauss.vert
+fn calculate_gauss_coefficients(sigma: f32, support: f32) -> vec2<f32> {
+    var coefficients = vec2<f32>(1.0 / (sqrt(2.0 * 3.14159265) * sigma),
+                                 exp(-0.5 / (sigma * sigma)));
+
+    // x: A, y: B, z: B²
+    var gauss_coefficient = vec3<f32>(coefficients, coefficients.y * coefficients.y);
+
+    // 积分 对覆盖到的像素权重求和
+    var gauss_coefficient_total = gauss_coefficient.x;
+
+    for (var i = 1.0; i <= 300.0; i += 2.0) {
+        if (i > support) {
+            break;
+        }
+        gauss_coefficient = vec3<f32>(gauss_coefficient.xy * gauss_coefficient.yz, gauss_coefficient.z);
+        var gauss_coefficient_subtotal = gauss_coefficient.x;
+        gauss_coefficient = vec3<f32>(gauss_coefficient.xy * gauss_coefficient.yz, gauss_coefficient.z);
+        gauss_coefficient_subtotal += gauss_coefficient.x;
+
+        // 除x=0的像素，其他像素都是对称的，所以乘2
+        gauss_coefficient_total += 2.0 * gauss_coefficient_subtotal;
+    }
+
+    coefficients.x = coefficients.x / gauss_coefficient_total;
+    return coefficients;
+}
+
+@vertex
+fn main(@location(0) position: vec2<f32>) -> VertexOutput {
+    var out: VertexOutput;
+
+    out.position = param.vertexMatrix * vec4<f32>(position * 2.0, 1.0, 1.0);
+    out.position.z = param.depth;
+
+    var vUv = position + 0.5;
+    vUv.y = 1.0 - vUv.y;
+
+    out.support = ceil(1.5 * param.blurRadius) * 2.0;
+    if (out.support > 0.0) {
+        // 以σ为blurRadius来计算权重值
+        out.vGaussCoefficients = calculate_gauss_coefficients(param.blurRadius, out.support);
+    } else {
+        // support不大于0，则默认为1.0
+        out.vGaussCoefficients = vec2<f32>(1.0, 1.0);
+    }
+
+    // 计算一个像素在纹理上的uv偏移（0~1的数）
+    out.vOffsetScale = mix(vec2<f32>(0.0, param.textureSize.y), vec2<f32>(param.textureSize.x, 0.0), step(0.01, param.horizontal));
+
+    // 像素单位计算到uv单位，因为是采用线性采样，为防止溢出，这里会有一定偏移
+    out.uvRect = vec4<f32>(param.diffuseMat.xy + param.textureSize * 0.5, param.diffuseMat.zw + param.diffuseMat.xy - param.textureSize * 0.5);
+
+    out.vUv = vUv * param.diffuseMat.zw + param.diffuseMat.xy;
+    return out;
+}
@@ -0,0 +1,65 @@
+struct Model {
+    vertexMatrix: mat4x4<f32>,
+    diffuseMat: vec4<f32>,
+
+    center: vec2<f32>,
+    offset: f32,
+    iteration: f32,
+
+    start: f32,
+    fade: f32,
+    depth: f32,
+    alpha: f32,
+
+    src_preimultiplied: f32,
+    dst_preimultiply: f32,
+    _wasm_0: f32,
+    _wasm_1: f32,
+};
+
+@group(0) @binding(0) var<uniform> param: Model;
+@group(0) @binding(1) var diffuseTex: texture_2d<f32>;
+@group(0) @binding(2) var sampler_diffuseTex: sampler;
+
+fn texColor(src: vec4<f32>) -> vec4<f32> {
+    return vec4<f32>(src.rgb / mix(1.0, src.a, step(0.5, param.src_preimultiplied)), src.a);
+}
+
+fn clampUV(uv: vec2<f32>, minUV: vec2<f32>, maxUV: vec2<f32>) -> vec2<f32> {
+    return clamp(uv, minUV, maxUV);
+}
+
+// 在非一致控制流中调用, 只能使用显式 LOD 采样
+fn loop_n(uv: vec2<f32>, diff: vec2<f32>) -> vec4<f32> {
+    var c = vec4<f32>(0.0);
+    var count = 0.0;
+
+    for (var i = 0; i < 32; i++) {
+        if (f32(i) == param.iteration) {
+            break;
+        }
+        count += 1.0;
+        c += texColor(textureSampleLevel(diffuseTex, sampler_diffuseTex, clampUV(uv + f32(i) * diff, param.diffuseMat.xy, param.diffuseMat.zw + param.diffuseMat.xy), 0.0));
+    }
+
+    return c / count;
+}
+
+@fragment
+fn main(@location(0) postiion_cs: vec2<f32>) -> @location(0) vec4<f32> {
+    let vMainUV = postiion_cs * param.diffuseMat.zw + param.diffuseMat.xy;
+
+    let direct = (postiion_cs - vec2<f32>(0.5)) * 2.0 - param.center;
+    let len = length(direct);
+    let strength = smoothstep(param.start, param.start + param.fade, len);
+    let diff = normalize(direct) * param.offset * strength;
+
+    var c: vec4<f32>;
+    if (0.001 < strength) {
+        c = loop_n(vMainUV, diff);
+    } else {
+        c = texColor(textureSampleLevel(diffuseTex, sampler_diffuseTex, vMainUV, 0.0));
+    }
+    c.a *= param.alpha;
+    return vec4<f32>(c.rgb * mix(1.0, c.a, step(0.5, param.dst_preimultiply)), c.a);
+}
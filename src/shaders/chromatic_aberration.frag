@@ -0,0 +1,73 @@
+#version 450
+
+#define SHADER_NAME fragment:ChromaticAberration
+
+layout(location = 0) in vec2 postiion_cs;
+
+layout(location = 0) out vec4 gl_FragColor;
+
+layout(set = 0, binding = 0) uniform Param {
+    mat4 vertexMatrix;
+    vec4 diffuseMat;
+
+    float centerx;
+    float centery;
+    float direct_x;
+    float direct_y;
+
+    vec2 texel;
+    float strength;
+    float falloff;
+
+    float mode;
+    float depth;
+    float alpha;
+    float src_preimultiplied;
+
+    float dst_preimultiply;
+    float _wasm_0;
+    float _wasm_1;
+    float _wasm_2;
+};
+
+
+layout(set = 0, binding = 1) uniform texture2D diffuseTex;
+layout(set = 0, binding = 2) uniform sampler sampler_diffuseTex;
+
+vec4 texColor(vec4 src) {
+    src.rgb /= mix(1., src.a, step(0.5, src_preimultiplied));
+    return src;
+}
+
+vec2 clampUV(vec2 uv, vec2 minUV, vec2 maxUV) {
+    return vec2(
+        clamp(uv.x, minUV.x, maxUV.x),
+        clamp(uv.y, minUV.y, maxUV.y)
+    );
+}
+
+void main() {
+    vec2 minUV = vec2(0.) * diffuseMat.zw + diffuseMat.xy;
+    vec2 maxUV = vec2(1.) * diffuseMat.zw + diffuseMat.xy;
+
+    vec2 vMainUV = postiion_cs * diffuseMat.zw + diffuseMat.xy;
+
+    vec2 local = postiion_cs * 2.0 - 1.0 - vec2(centerx, centery);
+    // 像素空间的径向方向
+    vec2 radial = local / texel;
+    radial = radial / max(length(radial), 0.0001);
+    vec2 dir = mix(radial, vec2(direct_x, direct_y), step(0.5, mode));
+
+    float fade = mix(1., pow(clamp(length(local), 0., 1.), falloff), step(0.0001, falloff));
+    vec2 offset = dir * strength * fade * texel * diffuseMat.zw;
+
+    vec4 r = texColor(texture(sampler2D(diffuseTex, sampler_diffuseTex), clampUV(vMainUV - offset, minUV, maxUV)));
+    vec4 g = texColor(texture(sampler2D(diffuseTex, sampler_diffuseTex), vMainUV));
+    vec4 b = texColor(texture(sampler2D(diffuseTex, sampler_diffuseTex), clampUV(vMainUV + offset, minUV, maxUV)));
+
+    // 各通道按自身 alpha 合成
+    float a = max(max(r.a, g.a), b.a);
+    gl_FragColor = vec4(vec3(r.r * r.a, g.g * g.a, b.b * b.a) / max(a, 0.00001), a);
+    gl_FragColor.a *= alpha;
+    gl_FragColor.rgb *= mix(1., gl_FragColor.a, step(0.5, dst_preimultiply));
+}
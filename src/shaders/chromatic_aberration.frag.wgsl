@@ -0,0 +1,61 @@
+struct Param {
+    vertexMatrix: mat4x4<f32>,
+    diffuseMat: vec4<f32>,
+
+    centerx: f32,
+    centery: f32,
+    direct_x: f32,
+    direct_y: f32,
+
+    texel: vec2<f32>,
+    strength: f32,
+    falloff: f32,
+
+    mode: f32,
+    depth: f32,
+    alpha: f32,
+    src_preimultiplied: f32,
+
+    dst_preimultiply: f32,
+    _wasm_0: f32,
+    _wasm_1: f32,
+    _wasm_2: f32,
+};
+
+@group(0) @binding(0) var<uniform> param: Param;
+@group(0) @binding(1) var diffuseTex: texture_2d<f32>;
+@group(0) @binding(2) var sampler_diffuseTex: sampler;
+
+fn texColor(src: vec4<f32>) -> vec4<f32> {
+    return vec4<f32>(src.rgb / mix(1.0, src.a, step(0.5, param.src_preimultiplied)), src.a);
+}
+
+fn clampUV(uv: vec2<f32>, minUV: vec2<f32>, maxUV: vec2<f32>) -> vec2<f32> {
+    return clamp(uv, minUV, maxUV);
+}
+
+@fragment
+fn main(@location(0) postiion_cs: vec2<f32>) -> @location(0) vec4<f32> {
+    let minUV = vec2<f32>(0.0) * param.diffuseMat.zw + param.diffuseMat.xy;
+    let maxUV = vec2<f32>(1.0) * param.diffuseMat.zw + param.diffuseMat.xy;
+
+    let vMainUV = postiion_cs * param.diffuseMat.zw + param.diffuseMat.xy;
+
+    let local = postiion_cs * 2.0 - 1.0 - vec2<f32>(param.centerx, param.centery);
+    // 像素空间的径向方向
+    var radial = local / param.texel;
+    radial = radial / max(length(radial), 0.0001);
+    let dir = mix(radial, vec2<f32>(param.direct_x, param.direct_y), step(0.5, param.mode));
+
+    let fade = mix(1.0, pow(clamp(length(local), 0.0, 1.0), param.falloff), step(0.0001, param.falloff));
+    let offset = dir * param.strength * fade * param.texel * param.diffuseMat.zw;
+
+    let r = texColor(textureSample(diffuseTex, sampler_diffuseTex, clampUV(vMainUV - offset, minUV, maxUV)));
+    let g = texColor(textureSample(diffuseTex, sampler_diffuseTex, vMainUV));
+    let b = texColor(textureSample(diffuseTex, sampler_diffuseTex, clampUV(vMainUV + offset, minUV, maxUV)));
+
+    // 各通道按自身 alpha 合成
+    let a = max(max(r.a, g.a), b.a) * param.alpha;
+    let rgb = vec3<f32>(r.r * r.a, g.g * g.a, b.b * b.a) / max(max(max(r.a, g.a), b.a), 0.00001);
+    return vec4<f32>(rgb * mix(1.0, a, step(0.5, param.dst_preimultiply)), a);
+}
@@ -0,0 +1,39 @@
+#version 450
+
+layout(location = 0) in vec2 position;
+layout(location = 0) out vec2 postiion_cs;
+
+layout(set = 0, binding = 0) uniform Param {
+    mat4 vertexMatrix;
+    vec4 diffuseMat;
+
+    float centerx;
+    float centery;
+    float direct_x;
+    float direct_y;
+
+    vec2 texel;
+    float strength;
+    float falloff;
+
+    float mode;
+    float depth;
+    float alpha;
+    float src_preimultiplied;
+
+    float dst_preimultiply;
+    float _wasm_0;
+    float _wasm_1;
+    float _wasm_2;
+};
+
+void main() {
+    vec4 positionUpdate = vec4(position * 2.0, 1.0, 1.0);
+
+    gl_Position = vertexMatrix * positionUpdate;
+    gl_Position.z = depth;
+    gl_Position.w = 1.0;
+
+    postiion_cs = position + 0.5;
+    postiion_cs.y = 1.0 - postiion_cs.y;
+}
\ No newline at end of file
@@ -15,7 +15,7 @@ layout(set = 0, binding = 0) uniform Param {
     float src_preimultiplied;
 
     float dst_preimultiply;
-    float _wasm_0;
+    float cutoff;
     float _wasm_1;
     float _wasm_2;
 };
@@ -236,6 +236,10 @@ void main() {
         factor = circle(vVertexPosition);
     }
 
+    // 写入模板时裁剪形状外不产生片元
+    if (factor < cutoff) {
+        discard;
+    }
     baseColor *= factor;
 
 
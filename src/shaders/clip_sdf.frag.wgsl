@@ -0,0 +1,237 @@
+struct Param {
+    vertexMatrix: mat4x4<f32>,
+    diffuseMat: vec4<f32>,
+
+    clipSdf0: vec4<f32>,
+    clipSdf1: vec4<f32>,
+    clipSdf2: vec4<f32>,
+    clipSdf3: vec4<f32>,
+
+    mode: f32,
+    depth: f32,
+    alpha: f32,
+    src_preimultiplied: f32,
+
+    dst_preimultiply: f32,
+    cutoff: f32,
+    _wasm_1: f32,
+    _wasm_2: f32,
+};
+
+@group(0) @binding(0) var<uniform> param: Param;
+@group(0) @binding(1) var diffuseTex: texture_2d<f32>;
+@group(0) @binding(2) var sampler_diffuseTex: sampler;
+
+fn texColor(src: vec4<f32>) -> vec4<f32> {
+    return vec4<f32>(src.rgb / mix(1.0, src.a, step(0.5, param.src_preimultiplied)), src.a);
+}
+
+// 根据 d, 抗锯齿, 返回 alpha值
+fn antialiase(d: f32) -> f32 {
+    let anti = 1.0 * fwidth(d);
+
+    // smoothstep(-a, a, d) 意思是 根据 d-值 将 [-a, a] 平滑到 [0, 1] 中
+    // d < -a, 全内部, 得到0, 这时期望 alpha = 1.0
+    // d > a, 全外部, 得到1, 这时期望 alpha = 0.0
+    return 1.0 - smoothstep(-anti, anti, d);
+}
+
+// Border Radius
+fn sdfEllipse(pt: vec2<f32>, center: vec2<f32>, ab: vec2<f32>) -> f32 {
+    let p = pt - center;
+
+    // 求 (1/a, 1/b)
+    let recAB = 1.0 / ab;
+    // 求 (x/a, y/b) = (x, y) * (1/a, 1/b)
+    let scale = p * recAB;
+
+    // 椭圆值 f = (x/a)^2 + (y/b)^2 - 1
+    return dot(scale, scale) - 1.0;
+}
+
+fn sdfRect(pt: vec2<f32>, wh: vec2<f32>) -> f32 {
+    let d = abs(pt) - wh;
+    return length(max(d, vec2<f32>(0.0))) + min(max(d.x, d.y), 0.0);
+}
+
+fn cross_pt(v1: vec2<f32>, v2: vec2<f32>) -> f32 {
+    return -(v1.x * v2.y - v1.y * v2.x);
+}
+// p0, p1, p2 是否 逆时针
+fn is_ccw(p0: vec2<f32>, p1: vec2<f32>, p2: vec2<f32>) -> bool {
+    let v1 = p1 - p0;
+    let v2 = p2 - p0;
+    let r = cross_pt(v1, v2);
+    return r > 0.0;
+}
+fn is_left_top(pt: vec2<f32>, wh: vec2<f32>, center: vec2<f32>) -> bool {
+    let pt0 = vec2<f32>(-wh.x, center.y);
+    let pt1 = vec2<f32>(center.x, -wh.y);
+    return is_ccw(pt, pt0, pt1);
+}
+fn is_top_right(pt: vec2<f32>, wh: vec2<f32>, center: vec2<f32>) -> bool {
+    let pt0 = vec2<f32>(center.x, -wh.y);
+    let pt1 = vec2<f32>(wh.x, center.y);
+    return is_ccw(pt, pt0, pt1);
+}
+fn is_right_bottom(pt: vec2<f32>, wh: vec2<f32>, center: vec2<f32>) -> bool {
+    let pt0 = vec2<f32>(wh.x, center.y);
+    let pt1 = vec2<f32>(center.x, wh.y);
+    return is_ccw(pt, pt0, pt1);
+}
+fn is_bottom_left(pt: vec2<f32>, wh: vec2<f32>, center: vec2<f32>) -> bool {
+    let pt0 = vec2<f32>(center.x, wh.y);
+    let pt1 = vec2<f32>(-wh.x, center.y);
+    return is_ccw(pt, pt0, pt1);
+}
+// fwidth 必须在统一控制流中求值, 所以四个角的 alpha 先全部算出, 再按象限选取
+fn antialiase_round_rect(pt: vec2<f32>, extent: vec2<f32>, offset1: vec2<f32>, offset2: vec2<f32>, offset3: vec2<f32>, offset4: vec2<f32>) -> f32 {
+    let a_rect = antialiase(sdfRect(pt, extent));
+
+    let center1 = vec2<f32>(-extent.x + offset1.x, -extent.y + offset1.y);
+    let a1 = antialiase(sdfEllipse(pt, center1, abs(offset1)));
+    let center2 = vec2<f32>(extent.x + offset2.x, -extent.y + offset2.y);
+    let a2 = antialiase(sdfEllipse(pt, center2, abs(offset2)));
+    let center3 = vec2<f32>(extent.x + offset3.x, extent.y + offset3.y);
+    let a3 = antialiase(sdfEllipse(pt, center3, abs(offset3)));
+    let center4 = vec2<f32>(-extent.x + offset4.x, extent.y + offset4.y);
+    let a4 = antialiase(sdfEllipse(pt, center4, abs(offset4)));
+
+    if (is_left_top(pt, extent, center1)) {
+        return min(a_rect, a1);
+    }
+    if (is_top_right(pt, extent, center2)) {
+        return min(a_rect, a2);
+    }
+    if (is_right_bottom(pt, extent, center3)) {
+        return min(a_rect, a3);
+    }
+    if (is_bottom_left(pt, extent, center4)) {
+        return min(a_rect, a4);
+    }
+    return a_rect;
+}
+
+fn border_radius(vVertexPosition: vec2<f32>) -> f32 {
+    let center = param.clipSdf0.xy;
+    let pos = vVertexPosition - center;
+    let top = param.clipSdf2;
+    let bottom = param.clipSdf3;
+    // 左上角
+    let c1 = vec2<f32>(max(0.01, top.y), max(0.01, top.x));
+    // 右上角
+    let c2 = vec2<f32>(-max(0.01, top.z), max(0.01, top.w));
+    // 右下角
+    let c3 = vec2<f32>(-max(0.01, bottom.y), -max(0.01, bottom.x));
+    // 左下角
+    let c4 = vec2<f32>(max(0.01, bottom.z), -max(0.01, bottom.w));
+
+    let extent = param.clipSdf0.zw;
+    return antialiase_round_rect(pos, extent, c1, c2, c3, c4);
+}
+
+// Sector
+// 扇形 sdf，负数在里面，正数在外面
+// pt 待求点
+// c 扇形 边缘处 距离 y轴的 夹角 sin, cos
+// r 半径
+// 参考 https://zhuanlan.zhihu.com/p/427587359
+fn sdfPie(pt: vec2<f32>, sc: vec2<f32>, r: f32) -> f32 {
+    var p = pt;
+    p.x = abs(p.x);
+    let d1 = length(p) - r;
+
+    if (sc.x < 0.0001) {
+        return select(sc.y, d1, abs(sc.y + 1.0) < 0.001);
+    }
+
+    let m = length(p - sc * clamp(dot(p, sc), 0.0, r));
+    let d2 = m * sign(sc.y * p.x - sc.x * p.y);
+    return max(d1, d2);
+}
+
+// 计算
+fn sector(vVertexPosition: vec2<f32>) -> f32 {
+    let center = param.clipSdf0.xy;
+
+    let axisSC = param.clipSdf2.xy;
+    let sc = param.clipSdf2.zw;
+
+    let r = param.clipSdf0.z;
+    var pos = vVertexPosition - center;
+
+    // 逆过来乘，将 扇形 乘回 到 对称轴 为 y轴 处
+    // 调整到 PI / 2 = 1.570796325
+    // cos(a- pi/2) = sin(a), sin(a - pi/2) = -cos(a)
+    // 要乘以 旋转矩阵 的 逆
+    pos = vec2<f32>(axisSC.x * pos.x - axisSC.y * pos.y, axisSC.y * pos.x + axisSC.x * pos.y);
+    let d = sdfPie(pos, sc, r);
+
+    return antialiase(d);
+}
+
+// rect
+// 计算alpha
+fn rect(vVertexPosition: vec2<f32>) -> f32 {
+    let center = param.clipSdf0.xy;
+
+    let uExtent = param.clipSdf0.zw;
+
+    let pos = vVertexPosition - center;
+    let d = sdfRect(pos, uExtent);
+
+    return antialiase(d);
+}
+
+// Ellipse
+// 计算alpha
+fn ellipse(vVertexPosition: vec2<f32>) -> f32 {
+    let e = param.clipSdf0;
+    let d = sdfEllipse(vVertexPosition, e.xy, e.zw);
+
+    return antialiase(d);
+}
+
+// Circle
+// 返回 coord 到 圆的 最短距离, 负值表示 在里面, 正值表示在外面
+fn sdfCircle(xy: vec2<f32>, r: f32) -> f32 {
+    return length(xy) - r;
+}
+
+// 计算alpha
+fn circle(vVertexPosition: vec2<f32>) -> f32 {
+    let center = param.clipSdf0.xy;
+    let radius = param.clipSdf0.z;
+    let pos = vVertexPosition - center;
+    let d = sdfCircle(pos, radius);
+
+    return antialiase(d);
+}
+
+@fragment
+fn main(@location(0) vUV: vec2<f32>, @location(1) vVertexPosition: vec2<f32>) -> @location(0) vec4<f32> {
+    var baseColor = texColor(textureSample(diffuseTex, sampler_diffuseTex, vUV));
+
+    var factor = 1.0;
+    if (param.mode > 3.5) {
+        factor = border_radius(vVertexPosition);
+    } else if (param.mode > 2.5) {
+        factor = sector(vVertexPosition);
+    } else if (param.mode > 1.5) {
+        factor = rect(vVertexPosition);
+    } else if (param.mode > 0.5) {
+        factor = ellipse(vVertexPosition);
+    } else {
+        factor = circle(vVertexPosition);
+    }
+
+    // 写入模板时裁剪形状外不产生片元
+    if (factor < param.cutoff) {
+        discard;
+    }
+    baseColor *= factor;
+
+    var c = baseColor;
+    c.a *= param.alpha;
+    return vec4<f32>(c.rgb * mix(1.0, c.a, step(0.5, param.dst_preimultiply)), c.a);
+}
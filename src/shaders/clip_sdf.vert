@@ -20,7 +20,7 @@ layout(set = 0, binding = 0) uniform Model {
     float src_preimultiplied;
 
     float dst_preimultiply;
-    float _wasm_0;
+    float cutoff;
     float _wasm_1;
     float _wasm_2;
 };
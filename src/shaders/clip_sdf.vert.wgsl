@@ -0,0 +1,43 @@
+struct Model {
+    vertexMatrix: mat4x4<f32>,
+    diffuseMat: vec4<f32>,
+
+    clipSdf0: vec4<f32>,
+    clipSdf1: vec4<f32>,
+    clipSdf2: vec4<f32>,
+    clipSdf3: vec4<f32>,
+
+    mode: f32,
+    depth: f32,
+    alpha: f32,
+    src_preimultiplied: f32,
+
+    dst_preimultiply: f32,
+    cutoff: f32,
+    _wasm_1: f32,
+    _wasm_2: f32,
+};
+
+@group(0) @binding(0) var<uniform> param: Model;
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) vUV: vec2<f32>,
+    @location(1) vVertexPosition: vec2<f32>,
+};
+
+@vertex
+fn main(@location(0) position: vec2<f32>) -> VertexOutput {
+    var out: VertexOutput;
+
+    out.position = param.vertexMatrix * vec4<f32>(position * 2.0, 1.0, 1.0);
+    out.position.z = param.depth;
+
+    out.vVertexPosition = vec2<f32>(position.x + 0.5, 0.5 - position.y) * param.clipSdf1.xy + param.clipSdf1.zw;
+
+    var uv = position + 0.5;
+    uv.y = 1.0 - uv.y;
+
+    out.vUV = uv * param.diffuseMat.zw + param.diffuseMat.xy;
+    return out;
+}
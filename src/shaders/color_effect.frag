@@ -121,25 +121,25 @@ void main() {
 
     vec4 c = texColor(texture(sampler2D(diffuseTex, sampler_diffuseTex), vMainUV));
 
-    if (flag1 > 0.0) {
-        c.rgb = colorBalance(c.rgb, vec3(color_balance_r, color_balance_g, color_balance_b));
-    }
+#ifdef COLOR_BALANCE
+    c.rgb = colorBalance(c.rgb, vec3(color_balance_r, color_balance_g, color_balance_b));
+#endif
 
-    if (flag2 > 0.0) {
-        c.rgb = applyHSV(c.rgb, vec3(hsb_h, hsb_s, hsb_b));
-    }
+#ifdef HSB
+    c.rgb = applyHSV(c.rgb, vec3(hsb_h, hsb_s, hsb_b));
+#endif
 
-    if (flag3 > 0.0) {
-        c.rgb = applyColorScale(c.rgb, scale_mid, scale_shadow_in, scale_highlight_in, scale_shadow_out, scale_highlight_out);
-    }
+#ifdef COLOR_SCALE
+    c.rgb = applyColorScale(c.rgb, scale_mid, scale_shadow_in, scale_highlight_in, scale_shadow_out, scale_highlight_out);
+#endif
 
-    if (flag4 > 0.0) {
-        c.rgb = vignette(c.rgb, postiion_cs, vignette_begin, vignette_end, vignette_scale, vec3(vignette_r, vignette_g, vignette_b));
-    }
+#ifdef VIGNETTE
+    c.rgb = vignette(c.rgb, postiion_cs, vignette_begin, vignette_end, vignette_scale, vec3(vignette_r, vignette_g, vignette_b));
+#endif
 
-    if (flag5 > 0.0) {
-        c.rgb = colorFilter(c.rgb, vec3(filter_r, filter_g, filter_b));
-    }
+#ifdef COLOR_FILTER
+    c.rgb = colorFilter(c.rgb, vec3(filter_r, filter_g, filter_b));
+#endif
 
     gl_FragColor = c;
     gl_FragColor.a *= alpha;
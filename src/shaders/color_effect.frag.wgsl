@@ -0,0 +1,144 @@
+struct ColorEffect {
+    vertexMatrix: mat4x4<f32>,
+    diffuseMat: vec4<f32>,
+
+    flag1: f32,
+    color_balance_r: f32,
+    color_balance_g: f32,
+    color_balance_b: f32,
+
+    flag2: f32,
+    hsb_h: f32,
+    hsb_s: f32,
+    hsb_b: f32,
+
+    flag3: f32,
+    scale_shadow_in: f32,
+    scale_shadow_out: f32,
+    scale_mid: f32,
+
+    scale_highlight_in: f32,
+    scale_highlight_out: f32,
+    flag4: f32,
+    vignette_begin: f32,
+
+    vignette_end: f32,
+    vignette_scale: f32,
+    vignette_r: f32,
+    vignette_g: f32,
+
+    vignette_b: f32,
+    flag5: f32,
+    filter_r: f32,
+    filter_g: f32,
+
+    filter_b: f32,
+    depth: f32,
+    alpha: f32,
+    src_preimultiplied: f32,
+
+    dst_preimultiply: f32,
+    _wasm_0: f32,
+    _wasm_1: f32,
+    _wasm_2: f32,
+};
+
+@group(0) @binding(0) var<uniform> param: ColorEffect;
+@group(0) @binding(1) var diffuseTex: texture_2d<f32>;
+@group(0) @binding(2) var sampler_diffuseTex: sampler;
+
+fn texColor(src: vec4<f32>) -> vec4<f32> {
+    return vec4<f32>(src.rgb / mix(1.0, src.a, step(0.5, param.src_preimultiplied)), src.a);
+}
+
+// COLOR_BALANCE, HSB, COLOR_SCALE, VIGNETTE, COLOR_FILTER 由加载时注入的常量决定
+
+fn rgb2hsv(c: vec3<f32>) -> vec3<f32> {
+    let K = vec4<f32>(0.0, -1.0 / 3.0, 2.0 / 3.0, -1.0);
+    let p = mix(vec4<f32>(c.bg, K.wz), vec4<f32>(c.gb, K.xy), step(c.b, c.g));
+    let q = mix(vec4<f32>(p.xyw, c.r), vec4<f32>(c.r, p.yzx), step(p.x, c.r));
+
+    let d = q.x - min(q.w, q.y);
+    let e = 1.0e-10;
+    return vec3<f32>(abs(q.z + (q.w - q.y) / (6.0 * d + e)), d / (q.x + e), q.x);
+}
+
+fn hsv2rgb(c: vec3<f32>) -> vec3<f32> {
+    let K = vec4<f32>(1.0, 2.0 / 3.0, 1.0 / 3.0, 3.0);
+    let p = abs(fract(c.xxx + K.xyz) * 6.0 - K.www);
+    return c.z * mix(K.xxx, clamp(p - K.xxx, vec3<f32>(0.0), vec3<f32>(1.0)), c.y);
+}
+
+fn colorBalance(rgb: vec3<f32>, cb: vec3<f32>) -> vec3<f32> {
+    return pow(rgb, cb);
+}
+
+fn colorFilter(rgb: vec3<f32>, cf: vec3<f32>) -> vec3<f32> {
+    return rgb * cf;
+}
+
+fn applyHSV(c: vec3<f32>, pHSV: vec3<f32>) -> vec3<f32> {
+    var hsv = rgb2hsv(c);
+    hsv.r += pHSV.r;
+    var rgb = hsv2rgb(hsv);
+
+    // Note: When saturate is greater than 0, the formula is different from PS
+    let gray = max(rgb.r, max(rgb.g, rgb.b)) + min(rgb.r, min(rgb.g, rgb.b));
+    rgb = mix(rgb, vec3<f32>(0.5 * gray), -pHSV.g);
+
+    if (pHSV.b >= 0.0) {
+        rgb = mix(rgb, vec3<f32>(1.0), pHSV.b);
+    } else {
+        rgb *= 1.0 + pHSV.b;
+    }
+
+    return rgb;
+}
+
+fn applyColorScale(color: vec3<f32>, csMid: f32, csInS: f32, csInH: f32, csOutS: f32, csOutH: f32) -> vec3<f32> {
+    var csD = csInH - csInS;
+    var rgb = clamp((color - vec3<f32>(csInS)) / csD, vec3<f32>(0.0), vec3<f32>(1.0));
+    rgb = pow(rgb, vec3<f32>(csMid, csMid, csMid));
+    csD = csOutH - csOutS;
+    rgb = clamp(rgb * csD + vec3<f32>(csOutS), vec3<f32>(0.0), vec3<f32>(1.0));
+    return rgb;
+}
+
+fn vignette(rgb: vec3<f32>, uv: vec2<f32>, start: f32, end: f32, scale: f32, color: vec3<f32>) -> vec3<f32> {
+    var dist = distance(uv, vec2<f32>(0.5, 0.5)) * 2.0;
+    dist = smoothstep(start, end, dist * scale);
+
+    return mix(rgb, color, dist);
+}
+
+@fragment
+fn main(@location(0) postiion_cs: vec2<f32>) -> @location(0) vec4<f32> {
+    let vMainUV = postiion_cs * param.diffuseMat.zw + param.diffuseMat.xy;
+
+    var c = texColor(textureSample(diffuseTex, sampler_diffuseTex, vMainUV));
+    var rgb = c.rgb;
+
+    if (COLOR_BALANCE) {
+        rgb = colorBalance(rgb, vec3<f32>(param.color_balance_r, param.color_balance_g, param.color_balance_b));
+    }
+
+    if (HSB) {
+        rgb = applyHSV(rgb, vec3<f32>(param.hsb_h, param.hsb_s, param.hsb_b));
+    }
+
+    if (COLOR_SCALE) {
+        rgb = applyColorScale(rgb, param.scale_mid, param.scale_shadow_in, param.scale_highlight_in, param.scale_shadow_out, param.scale_highlight_out);
+    }
+
+    if (VIGNETTE) {
+        rgb = vignette(rgb, postiion_cs, param.vignette_begin, param.vignette_end, param.vignette_scale, vec3<f32>(param.vignette_r, param.vignette_g, param.vignette_b));
+    }
+
+    if (COLOR_FILTER) {
+        rgb = colorFilter(rgb, vec3<f32>(param.filter_r, param.filter_g, param.filter_b));
+    }
+
+    c = vec4<f32>(rgb, c.a);
+    c.a *= param.alpha;
+    return vec4<f32>(c.rgb * mix(1.0, c.a, step(0.5, param.dst_preimultiply)), c.a);
+}
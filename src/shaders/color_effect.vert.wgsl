@@ -0,0 +1,63 @@
+struct ColorEffect {
+    vertexMatrix: mat4x4<f32>,
+    diffuseMat: vec4<f32>,
+
+    flag1: f32,
+    color_balance_r: f32,
+    color_balance_g: f32,
+    color_balance_b: f32,
+
+    flag2: f32,
+    hsb_h: f32,
+    hsb_s: f32,
+    hsb_b: f32,
+
+    flag3: f32,
+    scale_shadow_in: f32,
+    scale_shadow_out: f32,
+    scale_mid: f32,
+
+    scale_highlight_in: f32,
+    scale_highlight_out: f32,
+    flag4: f32,
+    vignette_begin: f32,
+
+    vignette_end: f32,
+    vignette_scale: f32,
+    vignette_r: f32,
+    vignette_g: f32,
+
+    vignette_b: f32,
+    flag5: f32,
+    filter_r: f32,
+    filter_g: f32,
+
+    filter_b: f32,
+    depth: f32,
+    alpha: f32,
+    src_preimultiplied: f32,
+
+    dst_preimultiply: f32,
+    _wasm_0: f32,
+    _wasm_1: f32,
+    _wasm_2: f32,
+};
+
+@group(0) @binding(0) var<uniform> param: ColorEffect;
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) postiion_cs: vec2<f32>,
+};
+
+@vertex
+fn main(@location(0) position: vec2<f32>) -> VertexOutput {
+    var out: VertexOutput;
+
+    out.position = param.vertexMatrix * vec4<f32>(position * 2.0, 1.0, 1.0);
+    out.position.z = param.depth;
+
+    out.postiion_cs = position + 0.5;
+    out.postiion_cs.y = 1.0 - out.postiion_cs.y;
+    return out;
+}
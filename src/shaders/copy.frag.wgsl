@@ -0,0 +1,79 @@
+struct Param {
+    vertexMatrix: mat4x4<f32>,
+    diffuseMat: vec4<f32>,
+
+    intensity: f32,
+    polygonN: f32,
+    radius: f32,
+    rotate: f32,
+
+    bgColorR: f32,
+    bgColorG: f32,
+    bgColorB: f32,
+    bgColorA: f32,
+
+    depth: f32,
+    alpha: f32,
+    src_preimultiplied: f32,
+    dst_preimultiply: f32,
+};
+
+@group(0) @binding(0) var<uniform> param: Param;
+@group(0) @binding(1) var diffuseTex: texture_2d<f32>;
+@group(0) @binding(2) var sampler_diffuseTex: sampler;
+
+const PI: f32 = 3.14159265358979323846;
+const TWO_PI: f32 = 6.2448530717958647692;
+
+fn texColor(src: vec4<f32>) -> vec4<f32> {
+    return vec4<f32>(src.rgb / mix(1.0, src.a, step(0.5, param.src_preimultiplied)), src.a);
+}
+
+fn rotate2d(angle: f32) -> mat2x2<f32> {
+    let c = cos(angle);
+    let s = sin(angle);
+    return mat2x2<f32>(c, -s,
+                       s, c);
+}
+
+// st: [-1, 1]
+fn shape(st: vec2<f32>, N: f32) -> f32 {
+    let a = atan2(st.x, st.y) + PI;
+    let r = TWO_PI / N;
+    return abs(cos(floor(0.5 + a / r) * r - a) * length(st));
+}
+
+fn circle(src: vec4<f32>, st: vec2<f32>, radius: f32, bgColor: vec4<f32>) -> vec4<f32> {
+    let intensity = 1.0 - smoothstep(radius, radius + 0.005, length(st * 2.0 - 1.0));
+
+    return mix(bgColor, src, intensity);
+}
+
+fn polygon(src: vec4<f32>, uv: vec2<f32>, scaling: f32, rotate: f32, N: f32, bgColor: vec4<f32>) -> vec4<f32> {
+    // [0, 1] => [-1, 1]
+    var st = uv * 2.0 - 1.0;
+    st /= scaling;
+    st = rotate2d(rotate) * st;
+    let intensity = 1.0 - smoothstep(1.0, 1.0 + 0.005, shape(st, N));
+
+    return mix(bgColor, src, intensity);
+}
+
+@fragment
+fn main(@location(0) postiion_cs: vec2<f32>) -> @location(0) vec4<f32> {
+    let vMainUV = postiion_cs * param.diffuseMat.zw + param.diffuseMat.xy;
+    let bgColor = vec4<f32>(param.bgColorR, param.bgColorG, param.bgColorB, param.bgColorA);
+    let src = texColor(textureSample(diffuseTex, sampler_diffuseTex, vMainUV));
+    var c = mix(
+        src,
+        mix(
+            circle(src, postiion_cs, param.radius, bgColor),
+            polygon(src, postiion_cs, param.radius, param.rotate, param.polygonN, bgColor),
+            step(2.5, param.polygonN)
+        ),
+        step(1.5, param.polygonN)
+    );
+
+    c.a *= param.alpha;
+    return vec4<f32>(c.rgb * mix(1.0, c.a, step(0.5, param.dst_preimultiply)) * param.intensity, c.a);
+}
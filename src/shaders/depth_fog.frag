@@ -0,0 +1,67 @@
+#version 450
+
+#define SHADER_NAME fragment:DepthFog
+
+layout(location = 0) in vec2 postiion_cs;
+
+layout(location = 0) out vec4 gl_FragColor;
+
+layout(set = 0, binding = 0) uniform Model {
+    mat4 vertexMatrix;
+    vec4 diffuseMat;
+
+    vec4 depthMat;
+    vec4 fogColor;
+
+    float near;
+    float far;
+    float fogStart;
+    float fogEnd;
+
+    float density;
+    float mode;
+    float reversed;
+    float depth;
+
+    float alpha;
+    float src_preimultiplied;
+    float dst_preimultiply;
+    float _wasm_0;
+};
+
+layout(set = 0, binding = 1) uniform texture2D diffuseTex;
+layout(set = 0, binding = 2) uniform sampler sampler_diffuseTex;
+layout(set = 0, binding = 3) uniform texture2D depthTex;
+layout(set = 0, binding = 4) uniform sampler sampler_depthTex;
+
+vec4 texColor(vec4 src) {
+    src.rgb /= mix(1., src.a, step(0.5, src_preimultiplied));
+    return src;
+}
+
+// 深度缓冲值 [0, 1] 转换为相机空间距离
+float linearDepth(vec2 uv) {
+    float d = texture(sampler2D(depthTex, sampler_depthTex), uv).r;
+    d = mix(d, 1.0 - d, step(0.5, reversed));
+    return near * far / (far - d * (far - near));
+}
+
+void main() {
+    vec2 vMainUV = postiion_cs * diffuseMat.zw + diffuseMat.xy;
+    vec2 vDepthUV = postiion_cs * depthMat.zw + depthMat.xy;
+
+    vec4 c = texColor(texture(sampler2D(diffuseTex, sampler_diffuseTex), vMainUV));
+    float z = linearDepth(vDepthUV);
+
+    // mode: 0 线性, 1 指数, 2 指数平方
+    float fogLinear = clamp((z - fogStart) / max(fogEnd - fogStart, 0.0001), 0., 1.);
+    float fogExp = 1.0 - exp(-density * z);
+    float fogExp2 = 1.0 - exp(-(density * z) * (density * z));
+    float factor = mix(fogLinear, mix(fogExp, fogExp2, step(1.5, mode)), step(0.5, mode)) * fogColor.a;
+
+    c.rgb = mix(c.rgb, fogColor.rgb, factor);
+
+    gl_FragColor = c;
+    gl_FragColor.a *= alpha;
+    gl_FragColor.rgb *= mix(1., gl_FragColor.a, step(0.5, dst_preimultiply));
+}
@@ -0,0 +1,58 @@
+struct Model {
+    vertexMatrix: mat4x4<f32>,
+    diffuseMat: vec4<f32>,
+
+    depthMat: vec4<f32>,
+    fogColor: vec4<f32>,
+
+    near: f32,
+    far: f32,
+    fogStart: f32,
+    fogEnd: f32,
+
+    density: f32,
+    mode: f32,
+    reversed: f32,
+    depth: f32,
+
+    alpha: f32,
+    src_preimultiplied: f32,
+    dst_preimultiply: f32,
+    _wasm_0: f32,
+};
+
+@group(0) @binding(0) var<uniform> param: Model;
+@group(0) @binding(1) var diffuseTex: texture_2d<f32>;
+@group(0) @binding(2) var sampler_diffuseTex: sampler;
+@group(0) @binding(3) var depthTex: texture_2d<f32>;
+@group(0) @binding(4) var sampler_depthTex: sampler;
+
+fn texColor(src: vec4<f32>) -> vec4<f32> {
+    return vec4<f32>(src.rgb / mix(1.0, src.a, step(0.5, param.src_preimultiplied)), src.a);
+}
+
+// 深度缓冲值 [0, 1] 转换为相机空间距离
+fn linearDepth(uv: vec2<f32>) -> f32 {
+    var d = textureSampleLevel(depthTex, sampler_depthTex, uv, 0.0).r;
+    d = mix(d, 1.0 - d, step(0.5, param.reversed));
+    return param.near * param.far / (param.far - d * (param.far - param.near));
+}
+
+@fragment
+fn main(@location(0) postiion_cs: vec2<f32>) -> @location(0) vec4<f32> {
+    let vMainUV = postiion_cs * param.diffuseMat.zw + param.diffuseMat.xy;
+    let vDepthUV = postiion_cs * param.depthMat.zw + param.depthMat.xy;
+
+    var c = texColor(textureSample(diffuseTex, sampler_diffuseTex, vMainUV));
+    let z = linearDepth(vDepthUV);
+
+    // mode: 0 线性, 1 指数, 2 指数平方
+    let fogLinear = clamp((z - param.fogStart) / max(param.fogEnd - param.fogStart, 0.0001), 0.0, 1.0);
+    let fogExp = 1.0 - exp(-param.density * z);
+    let fogExp2 = 1.0 - exp(-(param.density * z) * (param.density * z));
+    let factor = mix(fogLinear, mix(fogExp, fogExp2, step(1.5, param.mode)), step(0.5, param.mode)) * param.fogColor.a;
+
+    c = vec4<f32>(mix(c.rgb, param.fogColor.rgb, factor), c.a);
+    c.a *= param.alpha;
+    return vec4<f32>(c.rgb * mix(1.0, c.a, step(0.5, param.dst_preimultiply)), c.a);
+}
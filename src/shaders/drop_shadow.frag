@@ -0,0 +1,53 @@
+#version 450
+
+#define SHADER_NAME fragment:DropShadow
+
+layout(location = 0) in vec2 postiion_cs;
+
+layout(location = 0) out vec4 gl_FragColor;
+
+layout(set = 0, binding = 0) uniform Model {
+    mat4 vertexMatrix;
+    vec4 diffuseMat;
+
+    vec4 shadowMat;
+    vec4 shadowColor;
+
+    vec2 offset;
+    float depth;
+    float alpha;
+
+    float src_preimultiplied;
+    float dst_preimultiply;
+    float _wasm_0;
+    float _wasm_1;
+};
+
+layout(set = 0, binding = 1) uniform texture2D diffuseTex;
+layout(set = 0, binding = 2) uniform sampler sampler_diffuseTex;
+layout(set = 0, binding = 3) uniform texture2D shadowTex;
+layout(set = 0, binding = 4) uniform sampler sampler_shadowTex;
+
+vec4 texColor(vec4 src) {
+    src.rgb /= mix(1., src.a, step(0.5, src_preimultiplied));
+    return src;
+}
+
+void main() {
+    vec2 vMainUV = postiion_cs * diffuseMat.zw + diffuseMat.xy;
+    vec4 c = texColor(texture(sampler2D(diffuseTex, sampler_diffuseTex), vMainUV));
+
+    // 投影取偏移前位置的 alpha, 超出使用区域为透明
+    vec2 uv = postiion_cs - offset;
+    float inside = step(0., uv.x) * step(uv.x, 1.) * step(0., uv.y) * step(uv.y, 1.);
+    vec2 vShadowUV = clamp(uv, 0., 1.) * shadowMat.zw + shadowMat.xy;
+    float shadow = texture(sampler2D(shadowTex, sampler_shadowTex), vShadowUV).a * shadowColor.a * inside;
+
+    // 源内容叠加在投影之上
+    float a = c.a + shadow * (1. - c.a);
+    vec3 rgb = (c.rgb * c.a + shadowColor.rgb * shadow * (1. - c.a)) / max(a, 0.00001);
+
+    gl_FragColor = vec4(rgb, a);
+    gl_FragColor.a *= alpha;
+    gl_FragColor.rgb *= mix(1., gl_FragColor.a, step(0.5, dst_preimultiply));
+}
@@ -0,0 +1,46 @@
+struct Model {
+    vertexMatrix: mat4x4<f32>,
+    diffuseMat: vec4<f32>,
+
+    shadowMat: vec4<f32>,
+    shadowColor: vec4<f32>,
+
+    offset: vec2<f32>,
+    depth: f32,
+    alpha: f32,
+
+    src_preimultiplied: f32,
+    dst_preimultiply: f32,
+    _wasm_0: f32,
+    _wasm_1: f32,
+};
+
+@group(0) @binding(0) var<uniform> param: Model;
+@group(0) @binding(1) var diffuseTex: texture_2d<f32>;
+@group(0) @binding(2) var sampler_diffuseTex: sampler;
+@group(0) @binding(3) var shadowTex: texture_2d<f32>;
+@group(0) @binding(4) var sampler_shadowTex: sampler;
+
+fn texColor(src: vec4<f32>) -> vec4<f32> {
+    return vec4<f32>(src.rgb / mix(1.0, src.a, step(0.5, param.src_preimultiplied)), src.a);
+}
+
+@fragment
+fn main(@location(0) postiion_cs: vec2<f32>) -> @location(0) vec4<f32> {
+    let vMainUV = postiion_cs * param.diffuseMat.zw + param.diffuseMat.xy;
+    let c = texColor(textureSample(diffuseTex, sampler_diffuseTex, vMainUV));
+
+    // 投影取偏移前位置的 alpha, 超出使用区域为透明
+    let uv = postiion_cs - param.offset;
+    let inside = step(0.0, uv.x) * step(uv.x, 1.0) * step(0.0, uv.y) * step(uv.y, 1.0);
+    let vShadowUV = clamp(uv, vec2<f32>(0.0), vec2<f32>(1.0)) * param.shadowMat.zw + param.shadowMat.xy;
+    let shadow = textureSample(shadowTex, sampler_shadowTex, vShadowUV).a * param.shadowColor.a * inside;
+
+    // 源内容叠加在投影之上
+    let a = c.a + shadow * (1.0 - c.a);
+    let rgb = (c.rgb * c.a + param.shadowColor.rgb * shadow * (1.0 - c.a)) / max(a, 0.00001);
+
+    var out = vec4<f32>(rgb, a);
+    out.a *= param.alpha;
+    return vec4<f32>(out.rgb * mix(1.0, out.a, step(0.5, param.dst_preimultiply)), out.a);
+}
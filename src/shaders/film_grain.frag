@@ -0,0 +1,73 @@
+#version 450
+
+#define SHADER_NAME fragment:FilmGrain
+
+layout(location = 0) in vec2 postiion_cs;
+
+layout(location = 0) out vec4 gl_FragColor;
+
+layout(set = 0, binding = 0) uniform Param {
+    mat4 vertexMatrix;
+    vec4 diffuseMat;
+
+    vec2 texel;
+    float grain_size;
+    float intensity;
+
+    float luminance_response;
+    float monochrome;
+    float frame;
+    float seed;
+
+    float depth;
+    float alpha;
+    float src_preimultiplied;
+    float dst_preimultiply;
+};
+
+
+layout(set = 0, binding = 1) uniform texture2D diffuseTex;
+layout(set = 0, binding = 2) uniform sampler sampler_diffuseTex;
+
+vec4 texColor(vec4 src) {
+    src.rgb /= mix(1., src.a, step(0.5, src_preimultiplied));
+    return src;
+}
+
+// pcg3d - 整数哈希, 各平台结果一致
+vec3 hash(vec2 cell) {
+    uvec3 v = uvec3(uvec2(cell), uint(frame) + uint(seed) * 0x9E3779B9u);
+    v = v * 1664525u + 1013904223u;
+    v.x += v.y * v.z; v.y += v.z * v.x; v.z += v.x * v.y;
+    v ^= v >> 16u;
+    v.x += v.y * v.z; v.y += v.z * v.x; v.z += v.x * v.y;
+    return vec3(v) / 4294967295.0;
+}
+
+// 按颗粒尺寸插值的噪声 - [-0.5, 0.5]
+vec3 grain(vec2 pixel) {
+    vec2 p = pixel / grain_size;
+    vec2 i = floor(p);
+    vec2 f = smoothstep(0., 1., fract(p));
+    vec3 a = hash(i);
+    vec3 b = hash(i + vec2(1., 0.));
+    vec3 c = hash(i + vec2(0., 1.));
+    vec3 d = hash(i + vec2(1., 1.));
+    return mix(mix(a, b, f.x), mix(c, d, f.x), f.y) - 0.5;
+}
+
+void main() {
+    vec2 vMainUV = postiion_cs * diffuseMat.zw + diffuseMat.xy;
+    gl_FragColor = texColor(texture(sampler2D(diffuseTex, sampler_diffuseTex), vMainUV));
+
+    vec3 n = grain(floor(postiion_cs / texel));
+    n = mix(n, n.xxx, step(0.5, monochrome));
+
+    // 高光处颗粒减弱
+    float luma = dot(gl_FragColor.rgb, vec3(0.299, 0.587, 0.114));
+    float weight = mix(1., 1. - luma, luminance_response);
+    gl_FragColor.rgb = clamp(gl_FragColor.rgb + n * intensity * weight, 0., 1.);
+
+    gl_FragColor.a *= alpha;
+    gl_FragColor.rgb *= mix(1., gl_FragColor.a, step(0.5, dst_preimultiply));
+}
@@ -0,0 +1,65 @@
+struct Param {
+    vertexMatrix: mat4x4<f32>,
+    diffuseMat: vec4<f32>,
+
+    texel: vec2<f32>,
+    grain_size: f32,
+    intensity: f32,
+
+    luminance_response: f32,
+    monochrome: f32,
+    frame: f32,
+    seed: f32,
+
+    depth: f32,
+    alpha: f32,
+    src_preimultiplied: f32,
+    dst_preimultiply: f32,
+};
+
+@group(0) @binding(0) var<uniform> param: Param;
+@group(0) @binding(1) var diffuseTex: texture_2d<f32>;
+@group(0) @binding(2) var sampler_diffuseTex: sampler;
+
+fn texColor(src: vec4<f32>) -> vec4<f32> {
+    return vec4<f32>(src.rgb / mix(1.0, src.a, step(0.5, param.src_preimultiplied)), src.a);
+}
+
+// pcg3d - 整数哈希, 各平台结果一致
+fn hash(cell: vec2<f32>) -> vec3<f32> {
+    var v = vec3<u32>(vec2<u32>(cell), u32(param.frame) + u32(param.seed) * 0x9E3779B9u);
+    v = v * 1664525u + 1013904223u;
+    v.x += v.y * v.z; v.y += v.z * v.x; v.z += v.x * v.y;
+    v ^= v >> vec3<u32>(16u);
+    v.x += v.y * v.z; v.y += v.z * v.x; v.z += v.x * v.y;
+    return vec3<f32>(v) / 4294967295.0;
+}
+
+// 按颗粒尺寸插值的噪声 - [-0.5, 0.5]
+fn grain(pixel: vec2<f32>) -> vec3<f32> {
+    let p = pixel / param.grain_size;
+    let i = floor(p);
+    let f = smoothstep(vec2<f32>(0.0), vec2<f32>(1.0), fract(p));
+    let a = hash(i);
+    let b = hash(i + vec2<f32>(1.0, 0.0));
+    let c = hash(i + vec2<f32>(0.0, 1.0));
+    let d = hash(i + vec2<f32>(1.0, 1.0));
+    return mix(mix(a, b, f.x), mix(c, d, f.x), f.y) - 0.5;
+}
+
+@fragment
+fn main(@location(0) postiion_cs: vec2<f32>) -> @location(0) vec4<f32> {
+    let vMainUV = postiion_cs * param.diffuseMat.zw + param.diffuseMat.xy;
+    let c = texColor(textureSample(diffuseTex, sampler_diffuseTex, vMainUV));
+
+    var n = grain(floor(postiion_cs / param.texel));
+    n = mix(n, n.xxx, step(0.5, param.monochrome));
+
+    // 高光处颗粒减弱
+    let luma = dot(c.rgb, vec3<f32>(0.299, 0.587, 0.114));
+    let weight = mix(1.0, 1.0 - luma, param.luminance_response);
+    let rgb = clamp(c.rgb + n * param.intensity * weight, vec3<f32>(0.0), vec3<f32>(1.0));
+
+    let a = c.a * param.alpha;
+    return vec4<f32>(rgb * mix(1.0, a, step(0.5, param.dst_preimultiply)), a);
+}
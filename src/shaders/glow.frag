@@ -0,0 +1,56 @@
+#version 450
+
+#define SHADER_NAME fragment:Glow
+
+layout(location = 0) in vec2 postiion_cs;
+
+layout(location = 0) out vec4 gl_FragColor;
+
+layout(set = 0, binding = 0) uniform Model {
+    mat4 vertexMatrix;
+    vec4 diffuseMat;
+
+    vec4 glowMat;
+    vec4 glowColor;
+
+    float strength;
+    float mode;
+    float depth;
+    float alpha;
+
+    float src_preimultiplied;
+    float dst_preimultiply;
+    float _wasm_0;
+    float _wasm_1;
+};
+
+layout(set = 0, binding = 1) uniform texture2D diffuseTex;
+layout(set = 0, binding = 2) uniform sampler sampler_diffuseTex;
+layout(set = 0, binding = 3) uniform texture2D glowTex;
+layout(set = 0, binding = 4) uniform sampler sampler_glowTex;
+
+vec4 texColor(vec4 src) {
+    src.rgb /= mix(1., src.a, step(0.5, src_preimultiplied));
+    return src;
+}
+
+void main() {
+    vec2 vMainUV = postiion_cs * diffuseMat.zw + diffuseMat.xy;
+    vec2 vGlowUV = postiion_cs * glowMat.zw + glowMat.xy;
+    vec4 c = texColor(texture(sampler2D(diffuseTex, sampler_diffuseTex), vMainUV));
+    float blurred = texture(sampler2D(glowTex, sampler_glowTex), vGlowUV).a;
+
+    // mode: 0 外发光 - 源内容叠加在光晕之上
+    float outer = clamp(blurred * strength, 0., 1.) * glowColor.a;
+    float outerA = c.a + outer * (1. - c.a);
+    vec3 outerRGB = (c.rgb * c.a + glowColor.rgb * outer * (1. - c.a)) / max(outerA, 0.00001);
+
+    // mode: 1 内发光 - 越靠近边缘模糊后 alpha 越低, 光晕越强
+    float inner = clamp((1. - blurred) * strength, 0., 1.) * glowColor.a;
+    vec3 innerRGB = mix(c.rgb, glowColor.rgb, inner);
+
+    float isInner = step(0.5, mode);
+    gl_FragColor = vec4(mix(outerRGB, innerRGB, isInner), mix(outerA, c.a, isInner));
+    gl_FragColor.a *= alpha;
+    gl_FragColor.rgb *= mix(1., gl_FragColor.a, step(0.5, dst_preimultiply));
+}
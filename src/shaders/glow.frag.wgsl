@@ -0,0 +1,49 @@
+struct Model {
+    vertexMatrix: mat4x4<f32>,
+    diffuseMat: vec4<f32>,
+
+    glowMat: vec4<f32>,
+    glowColor: vec4<f32>,
+
+    strength: f32,
+    mode: f32,
+    depth: f32,
+    alpha: f32,
+
+    src_preimultiplied: f32,
+    dst_preimultiply: f32,
+    _wasm_0: f32,
+    _wasm_1: f32,
+};
+
+@group(0) @binding(0) var<uniform> param: Model;
+@group(0) @binding(1) var diffuseTex: texture_2d<f32>;
+@group(0) @binding(2) var sampler_diffuseTex: sampler;
+@group(0) @binding(3) var glowTex: texture_2d<f32>;
+@group(0) @binding(4) var sampler_glowTex: sampler;
+
+fn texColor(src: vec4<f32>) -> vec4<f32> {
+    return vec4<f32>(src.rgb / mix(1.0, src.a, step(0.5, param.src_preimultiplied)), src.a);
+}
+
+@fragment
+fn main(@location(0) postiion_cs: vec2<f32>) -> @location(0) vec4<f32> {
+    let vMainUV = postiion_cs * param.diffuseMat.zw + param.diffuseMat.xy;
+    let vGlowUV = postiion_cs * param.glowMat.zw + param.glowMat.xy;
+    let c = texColor(textureSample(diffuseTex, sampler_diffuseTex, vMainUV));
+    let blurred = textureSample(glowTex, sampler_glowTex, vGlowUV).a;
+
+    // mode: 0 外发光 - 源内容叠加在光晕之上
+    let outer = clamp(blurred * param.strength, 0.0, 1.0) * param.glowColor.a;
+    let outerA = c.a + outer * (1.0 - c.a);
+    let outerRGB = (c.rgb * c.a + param.glowColor.rgb * outer * (1.0 - c.a)) / max(outerA, 0.00001);
+
+    // mode: 1 内发光 - 越靠近边缘模糊后 alpha 越低, 光晕越强
+    let inner = clamp((1.0 - blurred) * param.strength, 0.0, 1.0) * param.glowColor.a;
+    let innerRGB = mix(c.rgb, param.glowColor.rgb, inner);
+
+    let isInner = step(0.5, param.mode);
+    var out = vec4<f32>(mix(outerRGB, innerRGB, isInner), mix(outerA, c.a, isInner));
+    out.a *= param.alpha;
+    return vec4<f32>(out.rgb * mix(1.0, out.a, step(0.5, param.dst_preimultiply)), out.a);
+}
@@ -0,0 +1,37 @@
+struct Model {
+    vertexMatrix: mat4x4<f32>,
+    diffuseMat: vec4<f32>,
+
+    strength: f32,
+    fade: f32,
+    depth: f32,
+    alpha: f32,
+
+    src_preimultiplied: f32,
+    dst_preimultiply: f32,
+    wasm0: f32,
+    wasm1: f32,
+};
+
+@group(0) @binding(0) var<uniform> param: Model;
+@group(0) @binding(1) var diffuseTex: texture_2d<f32>;
+@group(0) @binding(2) var sampler_diffuseTex: sampler;
+
+fn texColor(src: vec4<f32>) -> vec4<f32> {
+    return vec4<f32>(src.rgb / mix(1.0, src.a, step(0.5, param.src_preimultiplied)), src.a);
+}
+
+@fragment
+fn main(@location(0) postiion_cs: vec2<f32>, @location(1) vGlitch: vec4<f32>) -> @location(0) vec4<f32> {
+    let vMainUV = postiion_cs * param.diffuseMat.zw + param.diffuseMat.xy;
+
+    let diff_u = (1.0 - smoothstep(0.5 - param.fade / vGlitch.x, 0.5, abs(vGlitch.y - 0.5))) * param.strength * param.diffuseMat.z;
+
+    let src1 = texColor(textureSample(diffuseTex, sampler_diffuseTex, vMainUV));
+    let src2 = texColor(textureSample(diffuseTex, sampler_diffuseTex, vMainUV + vec2<f32>(vGlitch.z * diff_u, 0.0)));
+    let src3 = texColor(textureSample(diffuseTex, sampler_diffuseTex, vMainUV + vec2<f32>(vGlitch.z * diff_u * 1.5, 0.0)));
+
+    var c = vec4<f32>(src1.r, src2.g, src3.b, (src1.a + src2.a + src3.a) * 0.333334);
+    c.a *= param.alpha;
+    return vec4<f32>(c.rgb * mix(1.0, c.a, step(0.5, param.dst_preimultiply)), c.a);
+}
@@ -0,0 +1,42 @@
+struct Model {
+    vertexMatrix: mat4x4<f32>,
+    diffuseMat: vec4<f32>,
+
+    strength: f32,
+    fade: f32,
+    depth: f32,
+    alpha: f32,
+
+    src_preimultiplied: f32,
+    dst_preimultiply: f32,
+    wasm0: f32,
+    wasm1: f32,
+};
+
+@group(0) @binding(0) var<uniform> param: Model;
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) postiion_cs: vec2<f32>,
+    @location(1) vGlitch: vec4<f32>,
+};
+
+@vertex
+fn main(@location(0) position: vec2<f32>, @location(1) glitch: vec4<f32>) -> VertexOutput {
+    var out: VertexOutput;
+
+    var positionGlitch = position + 0.5; // [0, 1]
+    positionGlitch.y = positionGlitch.y * glitch.y + glitch.x;
+
+    let positionUpdate = vec4<f32>((positionGlitch - 0.5) * 2.0, 1.0, 1.0);
+
+    out.position = param.vertexMatrix * positionUpdate;
+    out.position.z = param.depth;
+
+    out.postiion_cs = positionGlitch;
+    out.postiion_cs.y = 1.0 - positionGlitch.y;
+
+    let halfSize = glitch.y / 2.0;
+    out.vGlitch = vec4<f32>(halfSize, position.y + 0.5, glitch.zw);
+    return out;
+}
@@ -0,0 +1,43 @@
+struct Param {
+    vertexMatrix: mat4x4<f32>,
+    diffuseMat: vec4<f32>,
+
+    maskMat: vec4<f32>,
+
+    maskFactor: f32,
+    maskMode: f32,
+    depth: f32,
+    alpha: f32,
+
+    src_preimultiplied: f32,
+    dst_preimultiply: f32,
+    wasm0: f32,
+    wasm1: f32,
+};
+
+@group(0) @binding(0) var<uniform> param: Param;
+@group(0) @binding(1) var diffuseTex: texture_2d<f32>;
+@group(0) @binding(2) var sampler_diffuseTex: sampler;
+@group(0) @binding(3) var maskTex: texture_2d<f32>;
+@group(0) @binding(4) var sampler_maskTex: sampler;
+
+fn texColor(src: vec4<f32>) -> vec4<f32> {
+    return vec4<f32>(src.rgb / mix(1.0, src.a, step(0.5, param.src_preimultiplied)), src.a);
+}
+
+@fragment
+fn main(@location(0) vUV: vec2<f32>, @location(1) vUV2: vec2<f32>) -> @location(0) vec4<f32> {
+    var baseColor = texColor(textureSample(diffuseTex, sampler_diffuseTex, vUV));
+    var maskValue = texColor(textureSample(maskTex, sampler_maskTex, vUV2)).r;
+    maskValue = maskValue * 0.990 + 0.005;
+
+    if (param.maskMode > 0.5) {
+        baseColor.a *= step(param.maskFactor, maskValue) * maskValue;
+    } else {
+        baseColor *= step(param.maskFactor, maskValue);
+    }
+
+    var c = baseColor;
+    c.a *= param.alpha;
+    return vec4<f32>(c.rgb * mix(1.0, c.a, step(0.5, param.dst_preimultiply)), c.a);
+}
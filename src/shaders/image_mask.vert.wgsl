@@ -0,0 +1,39 @@
+struct Model {
+    vertexMatrix: mat4x4<f32>,
+    diffuseMat: vec4<f32>,
+
+    maskMat: vec4<f32>,
+
+    maskFactor: f32,
+    maskMode: f32,
+    depth: f32,
+    alpha: f32,
+
+    src_preimultiplied: f32,
+    dst_preimultiply: f32,
+    wasm0: f32,
+    wasm1: f32,
+};
+
+@group(0) @binding(0) var<uniform> param: Model;
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) vUV: vec2<f32>,
+    @location(1) vUV2: vec2<f32>,
+};
+
+@vertex
+fn main(@location(0) position: vec2<f32>) -> VertexOutput {
+    var out: VertexOutput;
+
+    out.position = param.vertexMatrix * vec4<f32>(position * 2.0, 1.0, 1.0);
+    out.position.z = param.depth;
+
+    var uv = position + 0.5;
+    uv.y = 1.0 - uv.y;
+
+    out.vUV = uv * param.diffuseMat.zw + param.diffuseMat.xy;
+    out.vUV2 = uv * param.maskMat.zw + param.maskMat.xy;
+    return out;
+}
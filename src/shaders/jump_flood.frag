@@ -0,0 +1,112 @@
+#version 450
+
+#define SHADER_NAME fragment:JumpFlood
+
+layout(location = 0) in vec2 postiion_cs;
+
+layout(location = 0) out vec4 gl_FragColor;
+
+layout(set = 0, binding = 0) uniform Model {
+    mat4 vertexMatrix;
+    vec4 diffuseMat;
+
+    vec4 sourceMat;
+
+    vec2 size;
+    float jump;
+    float stage;
+
+    float spread;
+    float threshold;
+    float depth;
+    float alpha;
+
+    float src_preimultiplied;
+    float dst_preimultiply;
+    float _wasm_0;
+    float _wasm_1;
+};
+
+layout(set = 0, binding = 1) uniform texture2D diffuseTex;
+layout(set = 0, binding = 2) uniform sampler sampler_diffuseTex;
+layout(set = 0, binding = 3) uniform texture2D sourceTex;
+layout(set = 0, binding = 4) uniform sampler sampler_sourceTex;
+
+// 无种子
+const float NONE = 65535.;
+
+// 像素坐标按 16 位整数拆分到 4 个 8 位通道
+vec4 encode(vec2 p) {
+    return vec4(floor(p.x / 256.), mod(p.x, 256.), floor(p.y / 256.), mod(p.y, 256.)) / 255.;
+}
+vec2 decode(vec4 c) {
+    vec4 v = floor(c * 255. + 0.5);
+    return vec2(v.x * 256. + v.y, v.z * 256. + v.w);
+}
+float inRange(vec2 p) {
+    return step(0., p.x) * step(p.x, size.x - 1.) * step(0., p.y) * step(p.y, size.y - 1.);
+}
+vec4 fetchMain(vec2 p) {
+    return texture(sampler2D(diffuseTex, sampler_diffuseTex), clamp((p + 0.5) / size, 0., 1.) * diffuseMat.zw + diffuseMat.xy);
+}
+// 使用区域外视为形状外
+float insideMain(vec2 p) {
+    return step(threshold, fetchMain(p).a) * inRange(p);
+}
+float insideSource(vec2 p) {
+    return step(threshold, texture(sampler2D(sourceTex, sampler_sourceTex), clamp((p + 0.5) / size, 0., 1.) * sourceMat.zw + sourceMat.xy).a);
+}
+
+// 与任一 4 邻域内外状态不同的像素为种子
+vec4 seed(vec2 p) {
+    float current = insideMain(p);
+    float diff = abs(current - insideMain(p + vec2(1., 0.)))
+        + abs(current - insideMain(p - vec2(1., 0.)))
+        + abs(current - insideMain(p + vec2(0., 1.)))
+        + abs(current - insideMain(p - vec2(0., 1.)));
+    return encode(mix(vec2(NONE), p, step(0.5, diff)));
+}
+
+vec4 flood(vec2 p) {
+    vec2 best = vec2(NONE);
+    float bestDist = 1e20;
+    for (int j = -1; j <= 1; j++) {
+        for (int i = -1; i <= 1; i++) {
+            vec2 q = p + vec2(float(i), float(j)) * jump;
+            if (inRange(q) < 0.5) {
+                continue;
+            }
+            vec2 s = decode(fetchMain(q));
+            if (s.x < NONE) {
+                float d = distance(p, s);
+                if (d < bestDist) {
+                    bestDist = d;
+                    best = s;
+                }
+            }
+        }
+    }
+    return encode(best);
+}
+
+// 种子为边缘两侧像素中心, 距离补偿半个像素
+vec4 resolve(vec2 p) {
+    vec2 s = decode(fetchMain(p));
+    float d = s.x < NONE ? distance(p, s) + 0.5 : spread;
+    float sd = mix(d, -d, insideSource(p));
+    float v = clamp(0.5 - sd / (2. * spread), 0., 1.);
+    return vec4(v, v, v, 1.);
+}
+
+void main() {
+    vec2 p = floor(postiion_cs * size);
+    if (stage < 0.5) {
+        gl_FragColor = seed(p);
+    } else if (stage < 1.5) {
+        gl_FragColor = flood(p);
+    } else {
+        gl_FragColor = resolve(p);
+        gl_FragColor.a *= alpha;
+        gl_FragColor.rgb *= mix(1., gl_FragColor.a, step(0.5, dst_preimultiply));
+    }
+}
@@ -0,0 +1,105 @@
+struct Model {
+    vertexMatrix: mat4x4<f32>,
+    diffuseMat: vec4<f32>,
+
+    sourceMat: vec4<f32>,
+
+    size: vec2<f32>,
+    jump: f32,
+    stage: f32,
+
+    spread: f32,
+    threshold: f32,
+    depth: f32,
+    alpha: f32,
+
+    src_preimultiplied: f32,
+    dst_preimultiply: f32,
+    _wasm_0: f32,
+    _wasm_1: f32,
+};
+
+@group(0) @binding(0) var<uniform> param: Model;
+@group(0) @binding(1) var diffuseTex: texture_2d<f32>;
+@group(0) @binding(2) var sampler_diffuseTex: sampler;
+@group(0) @binding(3) var sourceTex: texture_2d<f32>;
+@group(0) @binding(4) var sampler_sourceTex: sampler;
+
+// 无种子
+const NONE: f32 = 65535.0;
+
+// 像素坐标按 16 位整数拆分到 4 个 8 位通道
+fn encode(p: vec2<f32>) -> vec4<f32> {
+    return vec4<f32>(floor(p.x / 256.0), p.x - floor(p.x / 256.0) * 256.0, floor(p.y / 256.0), p.y - floor(p.y / 256.0) * 256.0) / 255.0;
+}
+fn decode(c: vec4<f32>) -> vec2<f32> {
+    let v = floor(c * 255.0 + 0.5);
+    return vec2<f32>(v.x * 256.0 + v.y, v.z * 256.0 + v.w);
+}
+fn inRange(p: vec2<f32>) -> f32 {
+    return step(0.0, p.x) * step(p.x, param.size.x - 1.0) * step(0.0, p.y) * step(p.y, param.size.y - 1.0);
+}
+fn fetchMain(p: vec2<f32>) -> vec4<f32> {
+    return textureSampleLevel(diffuseTex, sampler_diffuseTex, clamp((p + 0.5) / param.size, vec2<f32>(0.0), vec2<f32>(1.0)) * param.diffuseMat.zw + param.diffuseMat.xy, 0.0);
+}
+// 使用区域外视为形状外
+fn insideMain(p: vec2<f32>) -> f32 {
+    return step(param.threshold, fetchMain(p).a) * inRange(p);
+}
+fn insideSource(p: vec2<f32>) -> f32 {
+    return step(param.threshold, textureSampleLevel(sourceTex, sampler_sourceTex, clamp((p + 0.5) / param.size, vec2<f32>(0.0), vec2<f32>(1.0)) * param.sourceMat.zw + param.sourceMat.xy, 0.0).a);
+}
+
+// 与任一 4 邻域内外状态不同的像素为种子
+fn seed(p: vec2<f32>) -> vec4<f32> {
+    let current = insideMain(p);
+    let diff = abs(current - insideMain(p + vec2<f32>(1.0, 0.0)))
+        + abs(current - insideMain(p - vec2<f32>(1.0, 0.0)))
+        + abs(current - insideMain(p + vec2<f32>(0.0, 1.0)))
+        + abs(current - insideMain(p - vec2<f32>(0.0, 1.0)));
+    return encode(mix(vec2<f32>(NONE), p, step(0.5, diff)));
+}
+
+fn flood(p: vec2<f32>) -> vec4<f32> {
+    var best = vec2<f32>(NONE);
+    var bestDist = 1e20;
+    for (var j = -1; j <= 1; j++) {
+        for (var i = -1; i <= 1; i++) {
+            let q = p + vec2<f32>(f32(i), f32(j)) * param.jump;
+            if (inRange(q) < 0.5) {
+                continue;
+            }
+            let s = decode(fetchMain(q));
+            if (s.x < NONE) {
+                let d = distance(p, s);
+                if (d < bestDist) {
+                    bestDist = d;
+                    best = s;
+                }
+            }
+        }
+    }
+    return encode(best);
+}
+
+// 种子为边缘两侧像素中心, 距离补偿半个像素
+fn resolve(p: vec2<f32>) -> vec4<f32> {
+    let s = decode(fetchMain(p));
+    let d = select(param.spread, distance(p, s) + 0.5, s.x < NONE);
+    let sd = mix(d, -d, insideSource(p));
+    let v = clamp(0.5 - sd / (2.0 * param.spread), 0.0, 1.0);
+    return vec4<f32>(v, v, v, 1.0);
+}
+
+@fragment
+fn main(@location(0) postiion_cs: vec2<f32>) -> @location(0) vec4<f32> {
+    let p = floor(postiion_cs * param.size);
+    if (param.stage < 0.5) {
+        return seed(p);
+    } else if (param.stage < 1.5) {
+        return flood(p);
+    }
+    var out = resolve(p);
+    out.a *= param.alpha;
+    return vec4<f32>(out.rgb * mix(1.0, out.a, step(0.5, param.dst_preimultiply)), out.a);
+}
@@ -0,0 +1,112 @@
+#version 450
+
+#define SHADER_NAME fragment:Outline
+
+layout(location = 0) in vec2 postiion_cs;
+
+layout(location = 0) out vec4 gl_FragColor;
+
+layout(set = 0, binding = 0) uniform Model {
+    mat4 vertexMatrix;
+    vec4 diffuseMat;
+
+    vec4 secondMat;
+    vec4 color;
+
+    vec2 texel;
+    float radius;
+    float width;
+
+    float placement;
+    float stage;
+    float depth;
+    float alpha;
+
+    float threshold;
+    float src_preimultiplied;
+    float dst_preimultiply;
+    float _wasm_0;
+};
+
+layout(set = 0, binding = 1) uniform texture2D diffuseTex;
+layout(set = 0, binding = 2) uniform sampler sampler_diffuseTex;
+layout(set = 0, binding = 3) uniform texture2D distanceTex;
+layout(set = 0, binding = 4) uniform sampler sampler_distanceTex;
+
+vec4 texColor(vec4 src) {
+    src.rgb /= mix(1., src.a, step(0.5, src_preimultiplied));
+    return src;
+}
+
+// 使用区域内的局部坐标是否有效
+float inRange(vec2 uv) {
+    return step(0., uv.x) * step(uv.x, 1.) * step(0., uv.y) * step(uv.y, 1.);
+}
+
+// pass 0: 水平方向到形状内 (r), 形状外 (g) 最近像素的距离 / radius; 使用区域外视为形状外
+vec4 horizontal() {
+    float dIn = radius;
+    float dOut = radius;
+    for (float i = -300.0; i <= 300.0; i += 1.0) {
+        if (abs(i) > radius) {
+            continue;
+        }
+        vec2 uv = postiion_cs + vec2(i * texel.x, 0.);
+        float a = texture(sampler2D(diffuseTex, sampler_diffuseTex), clamp(uv, 0., 1.) * diffuseMat.zw + diffuseMat.xy).a * inRange(uv);
+        float inside = step(threshold, a);
+        dIn = min(dIn, mix(radius, abs(i), inside));
+        dOut = min(dOut, mix(abs(i), radius, inside));
+    }
+    return vec4(dIn / radius, dOut / radius, 0., 1.);
+}
+
+// pass 1: 合并垂直方向 - 二维距离为 min(sqrt(dy^2 + dx(y)^2))
+vec4 vertical() {
+    float dIn = radius;
+    float dOut = radius;
+    for (float i = -300.0; i <= 300.0; i += 1.0) {
+        if (abs(i) > radius) {
+            continue;
+        }
+        vec2 uv = postiion_cs + vec2(0., i * texel.y);
+        float valid = inRange(uv);
+        vec2 d = texture(sampler2D(diffuseTex, sampler_diffuseTex), clamp(uv, 0., 1.) * diffuseMat.zw + diffuseMat.xy).rg * radius;
+        d = mix(vec2(radius, 0.), d, valid);
+        dIn = min(dIn, length(vec2(i, d.x)));
+        dOut = min(dOut, length(vec2(i, d.y)));
+    }
+    return vec4(dIn / radius, dOut / radius, 0., 1.);
+}
+
+// pass 2: 按有向距离绘制描边 - 形状外为正
+vec4 compose() {
+    vec4 c = texColor(texture(sampler2D(diffuseTex, sampler_diffuseTex), postiion_cs * diffuseMat.zw + diffuseMat.xy));
+    vec2 d = texture(sampler2D(distanceTex, sampler_distanceTex), postiion_cs * secondMat.zw + secondMat.xy).rg * radius;
+    float sd = mix(0.5 - d.y, d.x - 0.5, step(0.001, d.x));
+
+    // placement: 0 外, 1 内, 2 居中
+    float lo = mix(mix(0., -width, step(0.5, placement)), -width * 0.5, step(1.5, placement));
+    float hi = lo + width;
+    float coverage = smoothstep(lo - 0.5, lo + 0.5, sd) * (1. - smoothstep(hi - 0.5, hi + 0.5, sd));
+    float stroke = coverage * color.a;
+
+    // 外描边在源内容之下, 其余覆盖源内容
+    float isOutside = 1. - step(0.5, placement);
+    vec4 top = mix(vec4(color.rgb, stroke), c, isOutside);
+    vec4 bottom = mix(c, vec4(color.rgb, stroke), isOutside);
+    float a = top.a + bottom.a * (1. - top.a);
+    vec3 rgb = (top.rgb * top.a + bottom.rgb * bottom.a * (1. - top.a)) / max(a, 0.00001);
+    return vec4(rgb, a);
+}
+
+void main() {
+    if (stage < 0.5) {
+        gl_FragColor = horizontal();
+    } else if (stage < 1.5) {
+        gl_FragColor = vertical();
+    } else {
+        gl_FragColor = compose();
+        gl_FragColor.a *= alpha;
+        gl_FragColor.rgb *= mix(1., gl_FragColor.a, step(0.5, dst_preimultiply));
+    }
+}
@@ -0,0 +1,108 @@
+struct Model {
+    vertexMatrix: mat4x4<f32>,
+    diffuseMat: vec4<f32>,
+
+    secondMat: vec4<f32>,
+    color: vec4<f32>,
+
+    texel: vec2<f32>,
+    radius: f32,
+    width: f32,
+
+    placement: f32,
+    stage: f32,
+    depth: f32,
+    alpha: f32,
+
+    threshold: f32,
+    src_preimultiplied: f32,
+    dst_preimultiply: f32,
+    _wasm_0: f32,
+};
+
+@group(0) @binding(0) var<uniform> param: Model;
+@group(0) @binding(1) var diffuseTex: texture_2d<f32>;
+@group(0) @binding(2) var sampler_diffuseTex: sampler;
+@group(0) @binding(3) var distanceTex: texture_2d<f32>;
+@group(0) @binding(4) var sampler_distanceTex: sampler;
+
+fn texColor(src: vec4<f32>) -> vec4<f32> {
+    return vec4<f32>(src.rgb / mix(1.0, src.a, step(0.5, param.src_preimultiplied)), src.a);
+}
+
+// 使用区域内的局部坐标是否有效
+fn inRange(uv: vec2<f32>) -> f32 {
+    return step(0.0, uv.x) * step(uv.x, 1.0) * step(0.0, uv.y) * step(uv.y, 1.0);
+}
+
+fn sampleMain(uv: vec2<f32>) -> vec4<f32> {
+    return textureSampleLevel(diffuseTex, sampler_diffuseTex, clamp(uv, vec2<f32>(0.0), vec2<f32>(1.0)) * param.diffuseMat.zw + param.diffuseMat.xy, 0.0);
+}
+
+// pass 0: 水平方向到形状内 (r), 形状外 (g) 最近像素的距离 / radius; 使用区域外视为形状外
+fn horizontal(postiion_cs: vec2<f32>) -> vec4<f32> {
+    var dIn = param.radius;
+    var dOut = param.radius;
+    for (var i = -300.0; i <= 300.0; i += 1.0) {
+        if (abs(i) > param.radius) {
+            continue;
+        }
+        let uv = postiion_cs + vec2<f32>(i * param.texel.x, 0.0);
+        let a = sampleMain(uv).a * inRange(uv);
+        let inside = step(param.threshold, a);
+        dIn = min(dIn, mix(param.radius, abs(i), inside));
+        dOut = min(dOut, mix(abs(i), param.radius, inside));
+    }
+    return vec4<f32>(dIn / param.radius, dOut / param.radius, 0.0, 1.0);
+}
+
+// pass 1: 合并垂直方向 - 二维距离为 min(sqrt(dy^2 + dx(y)^2))
+fn vertical(postiion_cs: vec2<f32>) -> vec4<f32> {
+    var dIn = param.radius;
+    var dOut = param.radius;
+    for (var i = -300.0; i <= 300.0; i += 1.0) {
+        if (abs(i) > param.radius) {
+            continue;
+        }
+        let uv = postiion_cs + vec2<f32>(0.0, i * param.texel.y);
+        let valid = inRange(uv);
+        var d = sampleMain(uv).rg * param.radius;
+        d = mix(vec2<f32>(param.radius, 0.0), d, valid);
+        dIn = min(dIn, length(vec2<f32>(i, d.x)));
+        dOut = min(dOut, length(vec2<f32>(i, d.y)));
+    }
+    return vec4<f32>(dIn / param.radius, dOut / param.radius, 0.0, 1.0);
+}
+
+// pass 2: 按有向距离绘制描边 - 形状外为正
+fn compose(postiion_cs: vec2<f32>) -> vec4<f32> {
+    let c = texColor(textureSample(diffuseTex, sampler_diffuseTex, postiion_cs * param.diffuseMat.zw + param.diffuseMat.xy));
+    let d = textureSample(distanceTex, sampler_distanceTex, postiion_cs * param.secondMat.zw + param.secondMat.xy).rg * param.radius;
+    let sd = mix(0.5 - d.y, d.x - 0.5, step(0.001, d.x));
+
+    // placement: 0 外, 1 内, 2 居中
+    let lo = mix(mix(0.0, -param.width, step(0.5, param.placement)), -param.width * 0.5, step(1.5, param.placement));
+    let hi = lo + param.width;
+    let coverage = smoothstep(lo - 0.5, lo + 0.5, sd) * (1.0 - smoothstep(hi - 0.5, hi + 0.5, sd));
+    let stroke = coverage * param.color.a;
+
+    // 外描边在源内容之下, 其余覆盖源内容
+    let isOutside = 1.0 - step(0.5, param.placement);
+    let top = mix(vec4<f32>(param.color.rgb, stroke), c, isOutside);
+    let bottom = mix(c, vec4<f32>(param.color.rgb, stroke), isOutside);
+    let a = top.a + bottom.a * (1.0 - top.a);
+    let rgb = (top.rgb * top.a + bottom.rgb * bottom.a * (1.0 - top.a)) / max(a, 0.00001);
+    return vec4<f32>(rgb, a);
+}
+
+@fragment
+fn main(@location(0) postiion_cs: vec2<f32>) -> @location(0) vec4<f32> {
+    if (param.stage < 0.5) {
+        return horizontal(postiion_cs);
+    } else if (param.stage < 1.5) {
+        return vertical(postiion_cs);
+    }
+    var out = compose(postiion_cs);
+    out.a *= param.alpha;
+    return vec4<f32>(out.rgb * mix(1.0, out.a, step(0.5, param.dst_preimultiply)), out.a);
+}
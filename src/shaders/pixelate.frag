@@ -0,0 +1,89 @@
+#version 450
+
+#define SHADER_NAME fragment:Pixelate
+
+layout(location = 0) in vec2 postiion_cs;
+
+layout(location = 0) out vec4 gl_FragColor;
+
+layout(set = 0, binding = 0) uniform Param {
+    mat4 vertexMatrix;
+    vec4 diffuseMat;
+
+    vec2 texel;
+    float block_x;
+    float block_y;
+
+    float shape;
+    float average;
+    float depth;
+    float alpha;
+
+    float src_preimultiplied;
+    float dst_preimultiply;
+    float _wasm_0;
+    float _wasm_1;
+};
+
+
+layout(set = 0, binding = 1) uniform texture2D diffuseTex;
+layout(set = 0, binding = 2) uniform sampler sampler_diffuseTex;
+
+vec4 texColor(vec4 src) {
+    src.rgb /= mix(1., src.a, step(0.5, src_preimultiplied));
+    return src;
+}
+
+// 使用区域内的像素坐标采样 - 限制在使用区域内, 避免读取图集中相邻内容
+vec4 sampleAt(vec2 pixel) {
+    vec2 uv = clamp(pixel * texel, texel * 0.5, 1. - texel * 0.5);
+    return texColor(texture(sampler2D(diffuseTex, sampler_diffuseTex), uv * diffuseMat.zw + diffuseMat.xy));
+}
+
+// 六边形网格中最近的单元中心 - 单元宽高为 block
+vec2 hexCenter(vec2 pixel, vec2 block) {
+    vec2 scale = vec2(block.x, block.y / 1.1547005);
+    vec2 p = pixel / scale;
+    vec2 r = vec2(1., 1.7320508);
+    vec2 h = r * 0.5;
+    vec2 a = mod(p, r) - h;
+    vec2 b = mod(p - h, r) - h;
+    vec2 gv = dot(a, a) < dot(b, b) ? a : b;
+    return (p - gv) * scale;
+}
+
+void main() {
+    vec2 block = vec2(block_x, block_y);
+    vec2 pixel = postiion_cs / texel;
+
+    // shape: 0 矩形, 1 六边形, 2 圆形
+    vec2 center = (floor(pixel / block) + 0.5) * block;
+    if (shape > 0.5 && shape < 1.5) {
+        center = hexCenter(pixel, block);
+    }
+
+    vec4 c;
+    if (average > 0.5) {
+        // 单元范围内 4x4 采样, 按 alpha 加权
+        vec4 sum = vec4(0.);
+        for (int j = 0; j < 4; j++) {
+            for (int i = 0; i < 4; i++) {
+                vec4 s = sampleAt(center + (vec2(float(i), float(j)) + 0.5 - 2.) * 0.25 * block);
+                sum += vec4(s.rgb * s.a, s.a);
+            }
+        }
+        c = vec4(sum.rgb / max(sum.a, 0.00001), sum.a / 16.);
+    } else {
+        c = sampleAt(center);
+    }
+
+    if (shape > 1.5) {
+        float d = length((pixel - center) / (block * 0.5));
+        float aa = 2. / min(block.x, block.y);
+        c.a *= 1. - smoothstep(1. - aa, 1., d);
+    }
+
+    gl_FragColor = c;
+    gl_FragColor.a *= alpha;
+    gl_FragColor.rgb *= mix(1., gl_FragColor.a, step(0.5, dst_preimultiply));
+}
@@ -0,0 +1,84 @@
+struct Param {
+    vertexMatrix: mat4x4<f32>,
+    diffuseMat: vec4<f32>,
+
+    texel: vec2<f32>,
+    block_x: f32,
+    block_y: f32,
+
+    shape: f32,
+    average: f32,
+    depth: f32,
+    alpha: f32,
+
+    src_preimultiplied: f32,
+    dst_preimultiply: f32,
+    _wasm_0: f32,
+    _wasm_1: f32,
+};
+
+@group(0) @binding(0) var<uniform> param: Param;
+@group(0) @binding(1) var diffuseTex: texture_2d<f32>;
+@group(0) @binding(2) var sampler_diffuseTex: sampler;
+
+fn texColor(src: vec4<f32>) -> vec4<f32> {
+    return vec4<f32>(src.rgb / mix(1.0, src.a, step(0.5, param.src_preimultiplied)), src.a);
+}
+
+// 使用区域内的像素坐标采样 - 限制在使用区域内, 避免读取图集中相邻内容
+fn sampleAt(pixel: vec2<f32>) -> vec4<f32> {
+    let uv = clamp(pixel * param.texel, param.texel * 0.5, 1.0 - param.texel * 0.5);
+    return texColor(textureSampleLevel(diffuseTex, sampler_diffuseTex, uv * param.diffuseMat.zw + param.diffuseMat.xy, 0.0));
+}
+
+fn modf2(x: vec2<f32>, y: vec2<f32>) -> vec2<f32> {
+    return x - y * floor(x / y);
+}
+
+// 六边形网格中最近的单元中心 - 单元宽高为 block
+fn hexCenter(pixel: vec2<f32>, block: vec2<f32>) -> vec2<f32> {
+    let scale = vec2<f32>(block.x, block.y / 1.1547005);
+    let p = pixel / scale;
+    let r = vec2<f32>(1.0, 1.7320508);
+    let h = r * 0.5;
+    let a = modf2(p, r) - h;
+    let b = modf2(p - h, r) - h;
+    let gv = select(b, a, dot(a, a) < dot(b, b));
+    return (p - gv) * scale;
+}
+
+@fragment
+fn main(@location(0) postiion_cs: vec2<f32>) -> @location(0) vec4<f32> {
+    let block = vec2<f32>(param.block_x, param.block_y);
+    let pixel = postiion_cs / param.texel;
+
+    // shape: 0 矩形, 1 六边形, 2 圆形
+    var center = (floor(pixel / block) + 0.5) * block;
+    if (param.shape > 0.5 && param.shape < 1.5) {
+        center = hexCenter(pixel, block);
+    }
+
+    var c: vec4<f32>;
+    if (param.average > 0.5) {
+        // 单元范围内 4x4 采样, 按 alpha 加权
+        var sum = vec4<f32>(0.0);
+        for (var j = 0; j < 4; j++) {
+            for (var i = 0; i < 4; i++) {
+                let s = sampleAt(center + (vec2<f32>(f32(i), f32(j)) + 0.5 - 2.0) * 0.25 * block);
+                sum += vec4<f32>(s.rgb * s.a, s.a);
+            }
+        }
+        c = vec4<f32>(sum.rgb / max(sum.a, 0.00001), sum.a / 16.0);
+    } else {
+        c = sampleAt(center);
+    }
+
+    if (param.shape > 1.5) {
+        let d = length((pixel - center) / (block * 0.5));
+        let aa = 2.0 / min(block.x, block.y);
+        c.a *= 1.0 - smoothstep(1.0 - aa, 1.0, d);
+    }
+
+    let a = c.a * param.alpha;
+    return vec4<f32>(c.rgb * mix(1.0, a, step(0.5, param.dst_preimultiply)), a);
+}
@@ -0,0 +1,54 @@
+struct Param {
+    vertexMatrix: mat4x4<f32>,
+    diffuseMat: vec4<f32>,
+
+    centerx: f32,
+    centery: f32,
+    aspect_ratio: f32,
+    start: f32,
+
+    end: f32,
+    cycle: f32,
+    weight: f32,
+    depth: f32,
+
+    alpha: f32,
+    src_preimultiplied: f32,
+    dst_preimultiply: f32,
+    wasm2: f32,
+};
+
+@group(0) @binding(0) var<uniform> param: Param;
+@group(0) @binding(1) var diffuseTex: texture_2d<f32>;
+@group(0) @binding(2) var sampler_diffuseTex: sampler;
+
+fn texColor(src: vec4<f32>) -> vec4<f32> {
+    return vec4<f32>(src.rgb / mix(1.0, src.a, step(0.5, param.src_preimultiplied)), src.a);
+}
+
+fn clampUV(uv: vec2<f32>, minUV: vec2<f32>, maxUV: vec2<f32>) -> vec2<f32> {
+    return clamp(uv, minUV, maxUV);
+}
+
+@fragment
+fn main(@location(0) postiion_cs: vec2<f32>) -> @location(0) vec4<f32> {
+    let minUV = vec2<f32>(0.0) * param.diffuseMat.zw + param.diffuseMat.xy;
+    let maxUV = vec2<f32>(1.0) * param.diffuseMat.zw + param.diffuseMat.xy;
+
+    let vMainUV = postiion_cs * param.diffuseMat.zw + param.diffuseMat.xy;
+
+    var local = postiion_cs * 2.0 - 1.0;
+    local.y *= param.aspect_ratio;
+
+    let len = distance(local, vec2<f32>(param.centerx, param.centery));
+    let width = param.end - param.start;
+    var diff = max(width / 2.0, 0.1);
+
+    let fade = smoothstep(param.start, param.start + diff, len) * (1.0 - smoothstep(param.end - diff, param.end, len));
+    let t = (len - param.start) / width * param.cycle;
+    diff = fade * param.weight * sin(t * 3.141592653589793);
+
+    var c = texColor(textureSample(diffuseTex, sampler_diffuseTex, clampUV(vMainUV + diff * param.diffuseMat.zw, minUV, maxUV)));
+    c.a *= param.alpha;
+    return vec4<f32>(c.rgb * mix(1.0, c.a, step(0.5, param.dst_preimultiply)), c.a);
+}
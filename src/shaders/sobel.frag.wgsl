@@ -0,0 +1,71 @@
+struct Param {
+    vertexMatrix: mat4x4<f32>,
+    diffuseMat: vec4<f32>,
+
+    color: vec4<f32>,
+    bgColor: vec4<f32>,
+
+    uDiffUV: vec2<f32>,
+    clip: f32,
+    depth: f32,
+
+    alpha: f32,
+    src_preimultiplied: f32,
+    dst_preimultiply: f32,
+    wasm2: f32,
+};
+
+@group(0) @binding(0) var<uniform> param: Param;
+@group(0) @binding(1) var diffuseTex: texture_2d<f32>;
+@group(0) @binding(2) var sampler_diffuseTex: sampler;
+
+fn texColor(src: vec4<f32>) -> vec4<f32> {
+    return vec4<f32>(src.rgb / mix(1.0, src.a, step(0.5, param.src_preimultiplied)), src.a);
+}
+
+const S0: vec3<f32> = vec3<f32>(1.0, 2.0, 1.0);
+const S1: vec3<f32> = vec3<f32>(1.0, 0.0, -1.0);
+
+fn rgb2gray(rgb: vec3<f32>) -> f32 {
+    return 0.2126 * rgb.r + 0.7150 * rgb.g + 0.0722 * rgb.b;
+}
+
+fn clampUV(uv: vec2<f32>, minUV: vec2<f32>, maxUV: vec2<f32>) -> vec2<f32> {
+    return clamp(uv, minUV, maxUV);
+}
+
+fn gray(uv: vec2<f32>, minUV: vec2<f32>, maxUV: vec2<f32>) -> f32 {
+    return rgb2gray(texColor(textureSample(diffuseTex, sampler_diffuseTex, clampUV(uv, minUV, maxUV))).rgb);
+}
+
+@fragment
+fn main(@location(0) postiion_cs: vec2<f32>) -> @location(0) vec4<f32> {
+    let vMainUV = postiion_cs * param.diffuseMat.zw + param.diffuseMat.xy;
+    let minUV = vec2<f32>(0.0) * param.diffuseMat.zw + param.diffuseMat.xy;
+    let maxUV = vec2<f32>(1.0) * param.diffuseMat.zw + param.diffuseMat.xy;
+
+    let g00 = gray(vMainUV + param.uDiffUV * vec2<f32>(-1.0, -1.0), minUV, maxUV);
+    let g01 = gray(vMainUV + param.uDiffUV * vec2<f32>(-0.0, -1.0), minUV, maxUV);
+    let g02 = gray(vMainUV + param.uDiffUV * vec2<f32>( 1.0, -1.0), minUV, maxUV);
+    let g10 = gray(vMainUV + param.uDiffUV * vec2<f32>(-1.0, -0.0), minUV, maxUV);
+    let g11 = 0.0;
+    let g12 = gray(vMainUV + param.uDiffUV * vec2<f32>( 1.0, -0.0), minUV, maxUV);
+    let g20 = gray(vMainUV + param.uDiffUV * vec2<f32>(-1.0,  1.0), minUV, maxUV);
+    let g21 = gray(vMainUV + param.uDiffUV * vec2<f32>( 0.0,  1.0), minUV, maxUV);
+    let g22 = gray(vMainUV + param.uDiffUV * vec2<f32>( 1.0,  1.0), minUV, maxUV);
+
+    let a = mat3x3<f32>(
+        g00, g01, g02,
+        g10, g11, g12,
+        g21, g21, g22
+    );
+
+    let gx = dot(S0, (S1 * a));
+    let gy = dot(S1, (S0 * a));
+
+    let g = sqrt(gx * gx + gy * gy);
+
+    var c = mix(param.bgColor, param.color, step(param.clip, g) * g);
+    c.a *= param.alpha;
+    return vec4<f32>(c.rgb * mix(1.0, c.a, step(0.5, param.dst_preimultiply)), c.a);
+}
@@ -0,0 +1,74 @@
+use pi_render::renderer::pipeline::DepthStencilState;
+
+/// 最终合成的模板模式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EStencilMode {
+    /// 绘制产生片元处写入参考值, 不写颜色 - 生成裁剪蒙版
+    /// * 最后一个效果为 ClipSdf 时仅写入裁剪形状内
+    Write,
+    /// 仅在模板值与参考值满足比较条件处绘制
+    Test(wgpu::CompareFunction),
+}
+
+/// 最终合成的模板设置 - 通过 PostProcess::stencil 作用于 draw_final
+/// * 深度模板附件格式由调用方传入的 DepthStencilState 决定, 未传入时模板设置不生效
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PostprocessStencil {
+    pub mode: EStencilMode,
+    /// 参考值 - 绘制时设置到 RenderPass
+    pub reference: u32,
+    pub read_mask: u32,
+    pub write_mask: u32,
+}
+
+impl PostprocessStencil {
+    /// 写入模板 - 绘制区域模板值替换为 reference
+    pub fn write(reference: u32) -> Self {
+        Self { mode: EStencilMode::Write, reference, read_mask: 0xFF, write_mask: 0xFF }
+    }
+    /// 测试模板 - 仅在模板值等于 reference 处绘制
+    pub fn test(reference: u32) -> Self {
+        Self { mode: EStencilMode::Test(wgpu::CompareFunction::Equal), reference, read_mask: 0xFF, write_mask: 0 }
+    }
+    pub fn is_write(&self) -> bool {
+        self.mode == EStencilMode::Write
+    }
+    pub fn stencil_state(&self) -> wgpu::StencilState {
+        let face = match self.mode {
+            EStencilMode::Write => wgpu::StencilFaceState {
+                compare: wgpu::CompareFunction::Always,
+                fail_op: wgpu::StencilOperation::Keep,
+                depth_fail_op: wgpu::StencilOperation::Keep,
+                pass_op: wgpu::StencilOperation::Replace,
+            },
+            EStencilMode::Test(compare) => wgpu::StencilFaceState {
+                compare,
+                fail_op: wgpu::StencilOperation::Keep,
+                depth_fail_op: wgpu::StencilOperation::Keep,
+                pass_op: wgpu::StencilOperation::Keep,
+            },
+        };
+        let write_mask = match self.mode {
+            EStencilMode::Write => self.write_mask,
+            EStencilMode::Test(_) => 0,
+        };
+        wgpu::StencilState { front: face, back: face, read_mask: self.read_mask, write_mask }
+    }
+    /// 应用到调用方的深度模板状态
+    pub fn depth_stencil(&self, depth_stencil: Option<DepthStencilState>) -> Option<DepthStencilState> {
+        depth_stencil.map(|mut state| {
+            state.stencil = self.stencil_state();
+            if self.is_write() {
+                state.depth_write_enabled = false;
+            }
+            state
+        })
+    }
+    /// 写入模式不写颜色
+    pub fn color_state(&self, mut color_state: wgpu::ColorTargetState) -> wgpu::ColorTargetState {
+        if self.is_write() {
+            color_state.write_mask = wgpu::ColorWrites::empty();
+        }
+        color_state
+    }
+}
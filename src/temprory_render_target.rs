@@ -78,6 +78,50 @@ impl PostprocessTexture {
     pub fn size_eq_2(&self, rhs: &(u32, u32)) -> bool {
         self.use_w == rhs.0 && self.use_h == rhs.1
     }
+    /// 是否在同一纹理上 - 同一纹理不能同时作为采样源和渲染目标
+    pub fn same_texture(&self, rhs: &Self) -> bool {
+        std::ptr::eq(self.view(), rhs.view())
+    }
+}
+
+/// TempTargetPool 中的目标
+pub trait TTempTarget {
+    fn size_eq_2(&self, rhs: &(u32, u32)) -> bool;
+    fn same_texture(&self, rhs: &Self) -> bool;
+}
+impl TTempTarget for PostprocessTexture {
+    fn size_eq_2(&self, rhs: &(u32, u32)) -> bool {
+        PostprocessTexture::size_eq_2(self, rhs)
+    }
+    fn same_texture(&self, rhs: &Self) -> bool {
+        PostprocessTexture::same_texture(self, rhs)
+    }
+}
+
+/// 一次 calc 内中间结果的复用池
+/// * 中间结果的最后一个读取者记录后放回池中, 后续同尺寸的效果直接以其为目标
+/// * 长链效果因此在两个目标间交替, 而不是每个效果分配一个新目标
+pub struct TempTargetPool<T: TTempTarget = PostprocessTexture> {
+    free: Vec<T>,
+}
+impl<T: TTempTarget> Default for TempTargetPool<T> {
+    fn default() -> Self {
+        Self { free: vec![] }
+    }
+}
+impl<T: TTempTarget> TempTargetPool<T> {
+    /// 放回已无后续读取的中间结果
+    pub fn release(&mut self, target: T) {
+        self.free.push(target);
+    }
+    /// 取出与 size 同尺寸, 且与 source 不在同一纹理上的目标
+    pub fn acquire(&mut self, size: (u32, u32), source: &T) -> Option<T> {
+        let idx = self.free.iter().position(|item| item.size_eq_2(&size) && !item.same_texture(source))?;
+        Some(self.free.swap_remove(idx))
+    }
+    pub fn len(&self) -> usize {
+        self.free.len()
+    }
 }
 
 pub fn get_rect_info(rect: &Rectangle) -> (u32, u32, u32, u32) {
@@ -127,4 +171,58 @@ pub fn get_share_target_view(
     );
 
     srt
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// (纹理编号, 尺寸)
+    #[derive(Debug, PartialEq)]
+    struct Target(u32, (u32, u32));
+    impl TTempTarget for Target {
+        fn size_eq_2(&self, rhs: &(u32, u32)) -> bool {
+            self.1 == *rhs
+        }
+        fn same_texture(&self, rhs: &Self) -> bool {
+            self.0 == rhs.0
+        }
+    }
+
+    #[test]
+    fn chain_ping_pongs_between_two_targets() {
+        let mut pool = TempTargetPool::default();
+        let size = (64, 64);
+        // 第一个效果: src(0) -> 1, 第二个效果: 1 -> 2, 之后 1 已无读取者
+        let mut source = Target(2, size);
+        pool.release(Target(1, size));
+        let mut allocated = 2;
+        for _ in 0..6 {
+            let target = pool.acquire(size, &source).unwrap_or_else(|| { allocated += 1; Target(allocated, size) });
+            let last = std::mem::replace(&mut source, target);
+            pool.release(last);
+        }
+        assert_eq!(allocated, 2);
+        assert_eq!(pool.len(), 1);
+    }
+
+    #[test]
+    fn never_returns_the_texture_being_read() {
+        let mut pool = TempTargetPool::default();
+        pool.release(Target(1, (64, 64)));
+        assert_eq!(pool.acquire((64, 64), &Target(1, (32, 32))), None);
+        assert_eq!(pool.len(), 1);
+
+        pool.release(Target(2, (64, 64)));
+        assert_eq!(pool.acquire((64, 64), &Target(1, (32, 32))), Some(Target(2, (64, 64))));
+    }
+
+    #[test]
+    fn size_must_match() {
+        let mut pool = TempTargetPool::default();
+        pool.release(Target(1, (64, 64)));
+        assert_eq!(pool.acquire((32, 32), &Target(0, (64, 64))), None);
+        assert_eq!(pool.acquire((64, 64), &Target(0, (64, 64))), Some(Target(1, (64, 64))));
+        assert_eq!(pool.len(), 0);
+    }
+}
@@ -0,0 +1,101 @@
+/// 后处理时间 - 所有随时间变化的效果从此读取
+/// * calc 传入的 delta_time 经 advance 推进; 需要亚毫秒精度时可传 0 并直接调用 advance
+/// * 时间以 f64 毫秒记录, 长时间运行不溢出
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PostprocessTime {
+    /// 暂停时 advance 不推进时间
+    pub paused: bool,
+    /// 时间缩放 - 不小于 0
+    pub scale: f64,
+    /// 当前时间 - ms
+    time: f64,
+    /// 最近一次推进的时间 - ms
+    delta: f64,
+}
+
+impl Default for PostprocessTime {
+    fn default() -> Self {
+        Self { paused: false, scale: 1., time: 0., delta: 0. }
+    }
+}
+
+impl PostprocessTime {
+    /// 按实际间隔推进 - 返回缩放后的推进量 ms
+    pub fn advance(&mut self, delta_ms: f64) -> f64 {
+        self.delta = if self.paused { 0. } else { delta_ms.max(0.) * self.scale.max(0.) };
+        self.time += self.delta;
+        self.delta
+    }
+    /// 直接设置当前时间 - 用于拖动进度, 效果按新时间重新计算
+    pub fn set_time(&mut self, time_ms: f64) {
+        self.time = time_ms.max(0.);
+        self.delta = 0.;
+    }
+    /// 当前时间 - ms
+    pub fn time(&self) -> f64 {
+        self.time
+    }
+    /// 最近一次推进的时间 - ms
+    pub fn delta(&self) -> f64 {
+        self.delta
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paused_does_not_advance() {
+        let mut time = PostprocessTime::default();
+        time.advance(16.);
+        time.paused = true;
+        assert_eq!(time.advance(16.), 0.);
+        assert_eq!(time.time(), 16.);
+        assert_eq!(time.delta(), 0.);
+    }
+
+    #[test]
+    fn scale_applies_to_delta() {
+        let mut time = PostprocessTime::default();
+        time.scale = 0.5;
+        assert_eq!(time.advance(16.), 8.);
+        time.scale = 2.;
+        assert_eq!(time.advance(16.), 32.);
+        assert_eq!(time.time(), 40.);
+        // 负的缩放与间隔按 0 处理
+        time.scale = -1.;
+        assert_eq!(time.advance(16.), 0.);
+        time.scale = 1.;
+        assert_eq!(time.advance(-16.), 0.);
+        assert_eq!(time.time(), 40.);
+    }
+
+    #[test]
+    fn set_time_scrubs() {
+        let mut time = PostprocessTime::default();
+        time.advance(1_000.);
+        time.set_time(250.);
+        assert_eq!(time.time(), 250.);
+        assert_eq!(time.delta(), 0.);
+        time.advance(10.);
+        assert_eq!(time.time(), 260.);
+        time.set_time(-5.);
+        assert_eq!(time.time(), 0.);
+    }
+
+    #[test]
+    fn keeps_precision_past_u16_ms() {
+        let mut time = PostprocessTime::default();
+        // 两分钟的 16ms 帧, 超过 u16 毫秒的 65535
+        for _ in 0..7_500 {
+            time.advance(16.);
+        }
+        assert_eq!(time.time(), 120_000.);
+
+        // 亚毫秒间隔在长时间后仍然有效
+        time.set_time(3_600_000.);
+        time.advance(0.25);
+        assert_eq!(time.time(), 3_600_000.25);
+    }
+}